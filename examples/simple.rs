@@ -1,8 +1,6 @@
 use std::env;
 
-use philipshue::{
-    light::StateChange, DeviceType, Discover, DiscoveryUrl, Hue, HueBridge, HueError,
-};
+use philipshue::{light::StateChange, DeviceType, Discover, DiscoveryUrl, Hue, HueBridge};
 
 #[tokio::main]
 async fn main() {
@@ -15,7 +13,8 @@ async fn main() {
         Ok(bridge_url) => HueBridge::new(bridge_url),
         Err(_) => {
             // discover bridge; CAUTION: This discovery method has a rate limit so just discover a hue bridge once
-            let bridge: HueBridge = DiscoveryUrl::discover_one()
+            let bridge: HueBridge = DiscoveryUrl
+                .discover_one()
                 .await
                 .expect("Error while discovering bridge")
                 .into();
@@ -31,16 +30,9 @@ async fn main() {
         // login
         let hue = match bridge.login_to_hue(device_type).await {
             Ok(hue) => hue, // login request was successful
-            Err(HueError::ApiError(api_error)) => {
-                // there was an api error while login
-                if api_error.error_type == 101 {
-                    // this is the error_type if the user didn't press the link button, just rerun the login after he pressed the button
-                    panic!(
-                        "You have to press the link button of the bridge and restart this script."
-                    )
-                } else {
-                    panic!("Unknown api error: {api_error:?}"); // there was another api error
-                }
+            Err(e) if e.is_link_button_not_pressed() => {
+                // this is the error if the user didn't press the link button, just rerun the login after the button was pressed
+                panic!("You have to press the link button of the bridge and restart this script.")
             }
             Err(e) => panic!("{e:?}"), // there was another error
         };
@@ -2,6 +2,8 @@ use reqwest::Response;
 use serde::{de::DeserializeOwned, Deserialize};
 
 use crate::{
+    bridge_builder::HueBridgeBuilder,
+    certificate::{CertificateFingerprint, CertificatePin},
     discover::DiscoveredHueBridge,
     error::{ApiError, HueError},
 };
@@ -13,9 +15,13 @@ use crate::{
 ///
 /// let bridge = HueBridge::new("https://philips-hue.local".to_string());
 /// ```
+/// Use `HueBridge::builder` if you want to configure the http client
 #[derive(Clone, Debug)]
 pub struct HueBridge {
     pub bridge_url: String,
+    /// The client is cloned together with the `HueBridge`, all clones share the same connection pool
+    pub(crate) client: reqwest::Client,
+    pub(crate) certificate_pin: Option<CertificatePin>,
 }
 
 /// Some responses do include a `success` object or an `error` object containing an `ApiError`
@@ -27,6 +33,12 @@ pub(crate) enum DataResponse<T> {
     Error(ApiError),
 }
 
+/// Requests that create a new resource reply with the id of the created resource
+#[derive(Deserialize)]
+pub(crate) struct CreationResponse {
+    pub id: String,
+}
+
 /// A `DataResponse<T>` can be transformed to `Result<T, HueError>`
 impl<T> From<DataResponse<T>> for Result<T, HueError> {
     fn from(value: DataResponse<T>) -> Self {
@@ -38,12 +50,20 @@ impl<T> From<DataResponse<T>> for Result<T, HueError> {
 }
 
 impl HueBridge {
+    /// Create a `HueBridge` with the default http client
     pub fn new(bridge_url: String) -> Self {
-        Self { bridge_url }
+        HueBridgeBuilder::new(bridge_url)
+            .build()
+            .expect("the default http client should be valid")
+    }
+
+    /// Configure the http client (timeouts, user agent, connection pool and proxy) with a `HueBridgeBuilder`
+    pub fn builder(bridge_url: String) -> HueBridgeBuilder {
+        HueBridgeBuilder::new(bridge_url)
     }
 
     /// Parse the response from the `Response` struct which includes json which includes a `success` or an `error` object
-    pub(crate) async fn parse_response<'a, T>(&self, response: Response) -> Result<T, HueError>
+    pub(crate) async fn parse_response<T>(&self, response: Response) -> Result<T, HueError>
     where
         T: DeserializeOwned,
     {
@@ -56,18 +76,26 @@ impl HueBridge {
             .into()
     }
 
-    /// We need a custom client that *disables invalid SSL certs* because the hue bridge has a self signed certifcate
-    /// The client will have `{cargo package name}/{cargo package version}` as user agent
-    pub(crate) fn client(&self) -> reqwest::Client {
-        reqwest::ClientBuilder::new()
-            .danger_accept_invalid_certs(true)
-            .user_agent(concat!(
-                env!("CARGO_PKG_NAME"),
-                "/",
-                env!("CARGO_PKG_VERSION")
-            ))
-            .build()
-            .unwrap()
+    /// Parse all `success` or `error` objects of the response
+    pub(crate) async fn parse_responses<T>(
+        &self,
+        response: Response,
+    ) -> Result<Vec<DataResponse<T>>, HueError>
+    where
+        T: DeserializeOwned,
+    {
+        Ok(response.json().await?)
+    }
+
+    /// The sha256 fingerprint of the pinned certificate if `CertificateVerification::TrustOnFirstUse` is used.
+    /// Save it and pass it to `CertificateVerification::TrustOnFirstUse` the next time
+    pub fn certificate_fingerprint(&self) -> Option<CertificateFingerprint> {
+        *self.certificate_pin.as_ref()?.lock().unwrap()
+    }
+
+    /// The http client that is shared by all requests to the bridge
+    pub(crate) fn client(&self) -> &reqwest::Client {
+        &self.client
     }
 }
 
@@ -0,0 +1,151 @@
+use std::time::Duration;
+
+use crate::{bridge::HueBridge, certificate::CertificateVerification, error::HueError};
+
+/// The default user agent is `{cargo package name}/{cargo package version}`
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Build a `HueBridge` with a configured http client. The client is shared by all requests to the bridge
+/// ```
+/// use std::time::Duration;
+/// use philipshue::HueBridge;
+///
+/// let bridge = HueBridge::builder("https://philips-hue.local".to_string())
+///     .timeout(Duration::from_secs(10))
+///     .connect_timeout(Duration::from_secs(2))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct HueBridgeBuilder {
+    bridge_url: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: String,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    proxy: Option<reqwest::Proxy>,
+    certificate_verification: CertificateVerification,
+}
+
+impl HueBridgeBuilder {
+    pub fn new(bridge_url: String) -> Self {
+        Self {
+            bridge_url,
+            timeout: None,
+            connect_timeout: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            proxy: None,
+            certificate_verification: CertificateVerification::default(),
+        }
+    }
+
+    /// The timeout of a whole request, by default there is no timeout.
+    /// Don't set a timeout if you want to use long running requests like the event stream
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The timeout for connecting to the bridge, by default there is no timeout
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// The user agent is `{cargo package name}/{cargo package version}` by default
+    pub fn user_agent(mut self, user_agent: String) -> Self {
+        self.user_agent = user_agent;
+        self
+    }
+
+    /// The time an idle connection to the bridge is kept open
+    pub fn pool_idle_timeout(mut self, pool_idle_timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(pool_idle_timeout);
+        self
+    }
+
+    /// The maximum number of idle connections to the bridge
+    pub fn pool_max_idle_per_host(mut self, pool_max_idle_per_host: usize) -> Self {
+        self.pool_max_idle_per_host = Some(pool_max_idle_per_host);
+        self
+    }
+
+    /// Send all requests through a proxy
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Choose how the certificate of the bridge is verified, by default every certificate is accepted
+    pub fn certificate_verification(
+        mut self,
+        certificate_verification: CertificateVerification,
+    ) -> Self {
+        self.certificate_verification = certificate_verification;
+        self
+    }
+
+    /// By default we need a custom client that *disables invalid SSL certs* because the hue bridge has a self signed certifcate
+    pub fn build(self) -> Result<HueBridge, HueError> {
+        let mut builder = reqwest::ClientBuilder::new().user_agent(self.user_agent);
+        let certificate_pin = match self.certificate_verification.client_config() {
+            Some((tls_config, certificate_pin)) => {
+                builder = builder.use_preconfigured_tls(tls_config);
+                certificate_pin
+            }
+            None => {
+                builder = builder.danger_accept_invalid_certs(true);
+                None
+            }
+        };
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(pool_idle_timeout);
+        }
+        if let Some(pool_max_idle_per_host) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(pool_max_idle_per_host);
+        }
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(proxy);
+        }
+        Ok(HueBridge {
+            bridge_url: self.bridge_url,
+            client: builder.build()?,
+            certificate_pin,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_with_every_certificate_verification() {
+        for certificate_verification in [
+            CertificateVerification::AcceptInvalid,
+            CertificateVerification::BridgeId("001788fffe100491".to_string()),
+            CertificateVerification::TrustOnFirstUse(None),
+            CertificateVerification::TrustOnFirstUse(Some([1; 32])),
+        ] {
+            let bridge = HueBridgeBuilder::new("https://192.168.178.2".to_string())
+                .certificate_verification(certificate_verification.clone())
+                .build()
+                .unwrap_or_else(|e| panic!("{certificate_verification:?} failed: {e}"));
+            match certificate_verification {
+                CertificateVerification::TrustOnFirstUse(fingerprint) => {
+                    assert_eq!(bridge.certificate_fingerprint(), fingerprint)
+                }
+                _ => assert!(bridge.certificate_pin.is_none()),
+            }
+        }
+    }
+}
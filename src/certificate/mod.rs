@@ -0,0 +1,253 @@
+use std::{
+    sync::{Arc, Mutex, OnceLock},
+    time::SystemTime,
+};
+
+use rustls::{
+    client::{ServerCertVerified, ServerCertVerifier},
+    Certificate, ClientConfig, ServerName,
+};
+
+/// The root certificate of the philips hue bridges, see https://developers.meethue.com/develop/application-design-guidance/using-https/
+const ROOT_CERTIFICATE: &[u8] = include_bytes!("root-bridge.pem");
+
+/// The signature algorithms the bridge certificates are signed with
+static SIGNATURE_ALGORITHMS: &[&webpki::SignatureAlgorithm] = &[
+    &webpki::ECDSA_P256_SHA256,
+    &webpki::ECDSA_P256_SHA384,
+    &webpki::ECDSA_P384_SHA256,
+    &webpki::ECDSA_P384_SHA384,
+    &webpki::RSA_PKCS1_2048_8192_SHA256,
+];
+
+/// The sha256 fingerprint of a certificate
+pub type CertificateFingerprint = [u8; 32];
+
+/// How the certificate of the bridge is verified
+/// ```
+/// use philipshue::{CertificateVerification, HueBridge};
+///
+/// let bridge = HueBridge::builder("https://192.168.178.2".to_string())
+///     // the bridge id is the `id` of a `DiscoveredHueBridge`
+///     .certificate_verification(CertificateVerification::BridgeId("001788fffe100491".to_string()))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub enum CertificateVerification {
+    /// Accept every certificate, this is the default because the bridge has a self signed certificate
+    #[default]
+    AcceptInvalid,
+    /// Trust the root certificate of the philips hue bridges and check that the common name of the certificate is the bridge id.
+    /// Older bridges with a self signed certificate can't be verified this way
+    BridgeId(String),
+    /// Pin the sha256 fingerprint of the certificate. If the fingerprint is `None` the certificate of the first connection is pinned,
+    /// you can get it with `HueBridge::certificate_fingerprint` to save it for the next time
+    TrustOnFirstUse(Option<CertificateFingerprint>),
+}
+
+/// The fingerprint that is pinned by `CertificateVerification::TrustOnFirstUse`, it's shared between all clones of a `HueBridge`
+pub(crate) type CertificatePin = Arc<Mutex<Option<CertificateFingerprint>>>;
+
+impl CertificateVerification {
+    /// Build the tls config for the client, `None` if invalid certificates should be accepted
+    pub(crate) fn client_config(&self) -> Option<(ClientConfig, Option<CertificatePin>)> {
+        let (verifier, pin): (Arc<dyn ServerCertVerifier>, _) = match self {
+            Self::AcceptInvalid => return None,
+            Self::BridgeId(bridge_id) => (
+                Arc::new(BridgeIdVerifier::new(bridge_id, hue_root_certificate())),
+                None,
+            ),
+            Self::TrustOnFirstUse(fingerprint) => {
+                let pin = Arc::new(Mutex::new(*fingerprint));
+                (
+                    Arc::new(TrustOnFirstUseVerifier { pin: pin.clone() }),
+                    Some(pin),
+                )
+            }
+        };
+        let config = ClientConfig::builder()
+            .with_safe_defaults()
+            .with_custom_certificate_verifier(verifier)
+            .with_no_client_auth();
+        Some((config, pin))
+    }
+}
+
+/// The DER encoded root certificate of the philips hue bridges, it's parsed once on first use
+fn hue_root_certificate() -> &'static [u8] {
+    static ROOT: OnceLock<Vec<u8>> = OnceLock::new();
+    ROOT.get_or_init(|| {
+        rustls_pemfile::certs(&mut &ROOT_CERTIFICATE[..])
+            .ok()
+            .and_then(|certs| certs.into_iter().next())
+            .expect("the hue root certificate should be valid")
+    })
+}
+
+/// Verifies the certificate chain with the hue root certificate and the common name with the bridge id.
+/// The bridge is accessed by it's ip address so the usual hostname verification isn't possible
+struct BridgeIdVerifier {
+    bridge_id: String,
+    trust_anchor: webpki::TrustAnchor<'static>,
+}
+
+impl BridgeIdVerifier {
+    fn new(bridge_id: &str, root: &'static [u8]) -> Self {
+        Self {
+            bridge_id: bridge_id.to_lowercase(),
+            trust_anchor: webpki::TrustAnchor::try_from_cert_der(root)
+                .expect("the root certificate should be valid"),
+        }
+    }
+}
+
+impl ServerCertVerifier for BridgeIdVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        intermediates: &[Certificate],
+        _server_name: &ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        now: SystemTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let intermediates = intermediates
+            .iter()
+            .map(|certificate| certificate.0.as_slice())
+            .collect::<Vec<_>>();
+        webpki::EndEntityCert::try_from(end_entity.0.as_slice())
+            .and_then(|certificate| {
+                certificate.verify_for_usage(
+                    SIGNATURE_ALGORITHMS,
+                    std::slice::from_ref(&self.trust_anchor),
+                    &intermediates,
+                    webpki::Time::try_from(now).map_err(|_| webpki::Error::BadDerTime)?,
+                    webpki::KeyUsage::server_auth(),
+                    &[],
+                )
+            })
+            .map_err(|e| rustls::Error::General(format!("invalid bridge certificate: {e}")))?;
+
+        let (_, certificate) = x509_parser::parse_x509_certificate(&end_entity.0)
+            .map_err(|e| rustls::Error::General(e.to_string()))?;
+        let common_name = certificate
+            .subject()
+            .iter_common_name()
+            .next()
+            .and_then(|common_name| common_name.as_str().ok())
+            .ok_or_else(|| {
+                rustls::Error::General("the bridge certificate has no common name".to_string())
+            })?;
+        if common_name.to_lowercase() != self.bridge_id {
+            return Err(rustls::Error::General(format!(
+                "the bridge certificate belongs to the bridge {common_name} instead of {}",
+                self.bridge_id
+            )));
+        }
+        Ok(ServerCertVerified::assertion())
+    }
+}
+
+/// Pins the fingerprint of the first certificate and rejects other certificates afterwards
+struct TrustOnFirstUseVerifier {
+    pin: CertificatePin,
+}
+
+impl ServerCertVerifier for TrustOnFirstUseVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        _intermediates: &[Certificate],
+        _server_name: &ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: SystemTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let mut fingerprint = [0; 32];
+        fingerprint
+            .copy_from_slice(ring::digest::digest(&ring::digest::SHA256, &end_entity.0).as_ref());
+        let mut pin = self.pin.lock().unwrap();
+        match *pin {
+            Some(pinned) if pinned != fingerprint => Err(rustls::Error::General(
+                "the bridge certificate doesn't match the pinned certificate".to_string(),
+            )),
+            Some(_) => Ok(ServerCertVerified::assertion()),
+            None => {
+                *pin = Some(fingerprint);
+                Ok(ServerCertVerified::assertion())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::*;
+
+    /// A self signed root and two certificates it signed, the common names are `001788fffe100491` and `001788fffe999999`
+    const TEST_ROOT: &[u8] = include_bytes!("fixtures/test-root.der");
+    const TEST_BRIDGE: &[u8] = include_bytes!("fixtures/test-bridge.der");
+    const TEST_OTHER_BRIDGE: &[u8] = include_bytes!("fixtures/test-other-bridge.der");
+
+    fn verify(verifier: &dyn ServerCertVerifier, certificate: &[u8]) -> Result<(), rustls::Error> {
+        // the fixtures are valid from 2026 to 2126
+        let now = UNIX_EPOCH + Duration::from_secs(60 * 60 * 24 * 365 * 60);
+        verifier
+            .verify_server_cert(
+                &Certificate(certificate.to_vec()),
+                &[],
+                &ServerName::try_from("192.168.178.2").unwrap(),
+                &mut std::iter::empty(),
+                &[],
+                now,
+            )
+            .map(|_| ())
+    }
+
+    #[test]
+    fn hue_root_certificate_is_valid() {
+        webpki::TrustAnchor::try_from_cert_der(hue_root_certificate()).unwrap();
+    }
+
+    #[test]
+    fn bridge_id_matches_common_name() {
+        let verifier = BridgeIdVerifier::new("001788FFFE100491", TEST_ROOT);
+        assert!(verify(&verifier, TEST_BRIDGE).is_ok());
+        assert!(verify(&verifier, TEST_OTHER_BRIDGE).is_err());
+    }
+
+    #[test]
+    fn bridge_id_requires_the_root_certificate() {
+        let verifier = BridgeIdVerifier::new("001788fffe100491", hue_root_certificate());
+        assert!(verify(&verifier, TEST_BRIDGE).is_err());
+    }
+
+    #[test]
+    fn trust_on_first_use_pins_the_first_certificate() {
+        let pin = Arc::new(Mutex::new(None));
+        let verifier = TrustOnFirstUseVerifier { pin: pin.clone() };
+        assert!(verify(&verifier, TEST_BRIDGE).is_ok());
+        let fingerprint = pin
+            .lock()
+            .unwrap()
+            .expect("the certificate should be pinned");
+        assert_eq!(
+            fingerprint.as_ref(),
+            ring::digest::digest(&ring::digest::SHA256, TEST_BRIDGE).as_ref()
+        );
+        assert!(verify(&verifier, TEST_BRIDGE).is_ok());
+        assert!(verify(&verifier, TEST_OTHER_BRIDGE).is_err());
+        assert_eq!(*pin.lock().unwrap(), Some(fingerprint));
+    }
+
+    #[test]
+    fn trust_on_first_use_rejects_a_different_pin() {
+        let verifier = TrustOnFirstUseVerifier {
+            pin: Arc::new(Mutex::new(Some([0; 32]))),
+        };
+        assert!(verify(&verifier, TEST_BRIDGE).is_err());
+    }
+}
@@ -0,0 +1,100 @@
+use serde::Serialize;
+
+use crate::{error::HueError, Hue};
+
+impl Hue {
+    /// Change the config of the bridge
+    /// ```
+    /// use philipshue::{Hue, HueBridge, config::ConfigChange};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string());
+    ///     hue.update_config(
+    ///         ConfigChange::new()
+    ///             .name("Living room bridge".to_string())
+    ///             .timezone("Europe/Berlin".to_string())
+    ///     ).await.unwrap();
+    /// }
+    /// ```
+    pub async fn update_config(&self, config_change: ConfigChange) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .put(format!("{}/config", self.get_username_url()))
+                    .json(&config_change)
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Build `ConfigChange` objects to change the `BridgeConfig`
+#[derive(Default, Clone, Serialize)]
+pub struct ConfigChange {
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    value_name: Option<String>,
+    #[serde(rename = "timezone", skip_serializing_if = "Option::is_none")]
+    value_timezone: Option<String>,
+    #[serde(rename = "zigbeechannel", skip_serializing_if = "Option::is_none")]
+    value_zigbee_channel: Option<u8>,
+    #[serde(rename = "proxyaddress", skip_serializing_if = "Option::is_none")]
+    value_proxy_address: Option<String>,
+    #[serde(rename = "proxyport", skip_serializing_if = "Option::is_none")]
+    value_proxy_port: Option<u16>,
+    #[serde(rename = "touchlink", skip_serializing_if = "Option::is_none")]
+    value_touchlink: Option<bool>,
+}
+
+impl ConfigChange {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no value was set (every value is `None`)
+    pub fn is_empty(&self) -> bool {
+        self.value_name.is_none()
+            && self.value_timezone.is_none()
+            && self.value_zigbee_channel.is_none()
+            && self.value_proxy_address.is_none()
+            && self.value_proxy_port.is_none()
+            && self.value_touchlink.is_none()
+    }
+
+    pub fn name(mut self, name: String) -> Self {
+        self.value_name = Some(name);
+        self
+    }
+
+    /// A timezone like `Europe/Berlin`
+    pub fn timezone(mut self, timezone: String) -> Self {
+        self.value_timezone = Some(timezone);
+        self
+    }
+
+    /// The zigbee channel can be `11`, `15`, `20` or `25`
+    pub fn zigbee_channel(mut self, zigbee_channel: u8) -> Self {
+        self.value_zigbee_channel = Some(zigbee_channel);
+        self
+    }
+
+    /// The address of the proxy, set it to `none` to disable the proxy
+    pub fn proxy_address(mut self, proxy_address: String) -> Self {
+        self.value_proxy_address = Some(proxy_address);
+        self
+    }
+
+    pub fn proxy_port(mut self, proxy_port: u16) -> Self {
+        self.value_proxy_port = Some(proxy_port);
+        self
+    }
+
+    /// Start a touchlink, lights that are close to the bridge will be added to the bridge
+    pub fn touchlink(mut self, touchlink: bool) -> Self {
+        self.value_touchlink = Some(touchlink);
+        self
+    }
+}
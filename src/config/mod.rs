@@ -0,0 +1,38 @@
+use crate::{bridge::HueBridge, error::HueError, Hue};
+
+mod config_change;
+mod model;
+mod whitelist;
+
+pub use config_change::*;
+pub use model::*;
+pub use whitelist::WhitelistEntry;
+
+impl Hue {
+    /// Get the config of the bridge
+    pub async fn config(&self) -> Result<BridgeConfig, HueError> {
+        Ok(self
+            .bridge
+            .client()
+            .get(format!("{}/config", self.get_username_url()))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+}
+
+impl HueBridge {
+    /// Get the public part of the config, this is possible without a username so you can identify a bridge before login
+    pub async fn public_config(&self) -> Result<PublicBridgeConfig, HueError> {
+        Ok(self
+            .client()
+            .get(format!("{}/api/0/config", self.bridge_url))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+}
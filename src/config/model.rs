@@ -0,0 +1,75 @@
+use serde::Deserialize;
+
+use super::{whitelist::deserialize_whitelist, WhitelistEntry};
+
+#[derive(Deserialize)]
+pub struct BridgeConfig {
+    pub name: String,
+    #[serde(rename = "zigbeechannel")]
+    pub zigbee_channel: u8,
+    #[serde(rename = "bridgeid")]
+    pub bridge_id: String,
+    pub mac: String,
+    pub dhcp: bool,
+    #[serde(rename = "ipaddress")]
+    pub ip_address: String,
+    pub netmask: String,
+    pub gateway: String,
+    /// The address of the proxy or `none`
+    #[serde(rename = "proxyaddress")]
+    pub proxy_address: String,
+    #[serde(rename = "proxyport")]
+    pub proxy_port: u16,
+    #[serde(rename = "UTC")]
+    pub utc: String,
+    #[serde(rename = "localtime")]
+    pub local_time: String,
+    pub timezone: String,
+    #[serde(rename = "modelid")]
+    pub model_id: String,
+    #[serde(rename = "datastoreversion")]
+    pub datastore_version: String,
+    #[serde(rename = "swversion")]
+    pub software_version: String,
+    #[serde(rename = "apiversion")]
+    pub api_version: String,
+    /// `true` if the link button was pressed within the last 30 seconds
+    #[serde(rename = "linkbutton")]
+    pub link_button: bool,
+    #[serde(rename = "portalservices")]
+    pub portal_services: bool,
+    #[serde(rename = "portalconnection")]
+    pub portal_connection: String,
+    #[serde(rename = "factorynew")]
+    pub factory_new: bool,
+    #[serde(rename = "replacesbridgeid")]
+    pub replaces_bridge_id: Option<String>,
+    #[serde(rename = "starterkitid")]
+    pub starter_kit_id: Option<String>,
+    /// The users that are allowed to access the bridge
+    #[serde(deserialize_with = "deserialize_whitelist")]
+    pub whitelist: Vec<WhitelistEntry>,
+}
+
+/// The part of the `BridgeConfig` the bridge replies with without authorization
+#[derive(Deserialize)]
+pub struct PublicBridgeConfig {
+    pub name: String,
+    #[serde(rename = "bridgeid")]
+    pub bridge_id: String,
+    pub mac: String,
+    #[serde(rename = "modelid")]
+    pub model_id: String,
+    #[serde(rename = "datastoreversion")]
+    pub datastore_version: String,
+    #[serde(rename = "swversion")]
+    pub software_version: String,
+    #[serde(rename = "apiversion")]
+    pub api_version: String,
+    #[serde(rename = "factorynew")]
+    pub factory_new: bool,
+    #[serde(rename = "replacesbridgeid")]
+    pub replaces_bridge_id: Option<String>,
+    #[serde(rename = "starterkitid")]
+    pub starter_kit_id: Option<String>,
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::{error::HueError, Hue};
+
+/// A user (application key) that is allowed to access the bridge
+#[derive(Clone, Debug, Deserialize)]
+pub struct WhitelistEntry {
+    /// The username is the key of the entry in the whitelist
+    #[serde(skip)]
+    pub username: String,
+    /// The `DeviceType` that was used to login
+    pub name: String,
+    #[serde(rename = "create date")]
+    pub create_date: String,
+    #[serde(rename = "last use date")]
+    pub last_use_date: String,
+}
+
+/// The bridge replies with an object with the usernames as keys, the username is moved into the `WhitelistEntry`
+pub(crate) fn deserialize_whitelist<'de, D>(
+    deserializer: D,
+) -> Result<Vec<WhitelistEntry>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(
+        HashMap::<String, WhitelistEntry>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(username, entry)| WhitelistEntry { username, ..entry })
+            .collect(),
+    )
+}
+
+impl Hue {
+    /// Get all users that are allowed to access the bridge
+    pub async fn whitelist(&self) -> Result<Vec<WhitelistEntry>, HueError> {
+        Ok(self.config().await?.whitelist)
+    }
+
+    /// Revoke the access of the user `username`.
+    /// You can also delete the username of this `Hue` object, every request with this `Hue` object will fail afterwards
+    pub async fn delete_whitelist_entry(&self, username: &str) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .delete(format!(
+                        "{}/config/whitelist/{username}",
+                        self.get_username_url()
+                    ))
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+}
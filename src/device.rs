@@ -6,14 +6,34 @@ use serde::Serialize;
 ///
 /// let device_type = DeviceType::new("my_device_name".to_string());
 /// ```
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 pub struct DeviceType {
     #[serde(rename = "devicetype")]
     pub device_type: String,
+    /// Request a `clientkey` during login, the key is needed for entertainment streaming
+    #[serde(
+        rename = "generateclientkey",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub generate_client_key: bool,
 }
 
 impl DeviceType {
     pub fn new(device_type: String) -> Self {
-        Self { device_type }
+        Self {
+            device_type,
+            generate_client_key: false,
+        }
+    }
+
+    /// If `true` the bridge replies with a `clientkey` after login
+    /// ```
+    /// use philipshue::DeviceType;
+    ///
+    /// let device_type = DeviceType::new("my_device_name".to_string()).generate_client_key(true);
+    /// ```
+    pub fn generate_client_key(mut self, generate_client_key: bool) -> Self {
+        self.generate_client_key = generate_client_key;
+        self
     }
 }
@@ -0,0 +1,331 @@
+use futures_util::{stream::FuturesUnordered, StreamExt};
+
+use crate::{Discover, DiscoveredHueBridge, HueError};
+
+/// How the strategies of a `CompositeDiscovery` are run
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DiscoveryMode {
+    /// Run all strategies at the same time
+    #[default]
+    Parallel,
+    /// Run the strategies one after another in the order they were added
+    Fallback,
+}
+
+/// Combine several discovery strategies. The results are merged by the bridge id and
+/// `DiscoveredHueBridge::discovered_by` contains every method that found the bridge.
+/// The discovery stops as soon as `min_bridges` bridges were found.
+/// ```
+/// use philipshue::{CompositeDiscovery, Discover, DiscoveryMdns, DiscoveryMode, DiscoverySsdp, DiscoveryUrl};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let bridge = CompositeDiscovery::new()
+///         .strategy(DiscoveryMdns::new())
+///         .strategy(DiscoverySsdp::new())
+///         .strategy(DiscoveryUrl)
+///         .mode(DiscoveryMode::Fallback)
+///         .discover_one()
+///         .await
+///         .unwrap();
+/// }
+/// ```
+pub struct CompositeDiscovery {
+    strategies: Vec<Box<dyn Discover>>,
+    mode: DiscoveryMode,
+    min_bridges: usize,
+}
+
+impl Default for CompositeDiscovery {
+    fn default() -> Self {
+        Self {
+            strategies: Vec::new(),
+            mode: DiscoveryMode::default(),
+            min_bridges: 1,
+        }
+    }
+}
+
+impl CompositeDiscovery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a discovery strategy
+    pub fn strategy<D>(mut self, strategy: D) -> Self
+    where
+        D: Discover + 'static,
+    {
+        self.strategies.push(Box::new(strategy));
+        self
+    }
+
+    /// `DiscoveryMode::Parallel` by default
+    pub fn mode(mut self, mode: DiscoveryMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Stop the discovery after this number of bridges was found, `1` by default.
+    /// Set it to `usize::MAX` to wait for all strategies
+    pub fn min_bridges(mut self, min_bridges: usize) -> Self {
+        self.min_bridges = min_bridges;
+        self
+    }
+}
+
+/// Add the bridges to the known bridges, the ids are compared case insensitive because not every method uses the same case
+pub(super) fn merge(
+    known_bridges: &mut Vec<DiscoveredHueBridge>,
+    bridges: Vec<DiscoveredHueBridge>,
+) {
+    for bridge in bridges {
+        match known_bridges
+            .iter_mut()
+            .find(|known| known.id.eq_ignore_ascii_case(&bridge.id))
+        {
+            Some(known) => {
+                if known.model_id.is_none() {
+                    known.model_id = bridge.model_id;
+                }
+                if known.api_version.is_none() {
+                    known.api_version = bridge.api_version;
+                }
+                for method in bridge.discovered_by {
+                    if !known.discovered_by.contains(&method) {
+                        known.discovered_by.push(method);
+                    }
+                }
+            }
+            None => known_bridges.push(bridge),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Discover for CompositeDiscovery {
+    /// The error of a failed strategy is only returned if no strategy found a bridge
+    async fn discover(&self) -> Result<Vec<DiscoveredHueBridge>, HueError> {
+        let mut bridges = Vec::new();
+        let mut last_error = None;
+        match self.mode {
+            DiscoveryMode::Parallel => {
+                let mut discoveries = self
+                    .strategies
+                    .iter()
+                    .map(|strategy| strategy.discover())
+                    .collect::<FuturesUnordered<_>>();
+                while let Some(result) = discoveries.next().await {
+                    match result {
+                        Ok(discovered) => merge(&mut bridges, discovered),
+                        Err(e) => last_error = Some(e),
+                    }
+                    if bridges.len() >= self.min_bridges {
+                        break;
+                    }
+                }
+            }
+            DiscoveryMode::Fallback => {
+                for strategy in &self.strategies {
+                    match strategy.discover().await {
+                        Ok(discovered) => merge(&mut bridges, discovered),
+                        Err(e) => last_error = Some(e),
+                    }
+                    if bridges.len() >= self.min_bridges {
+                        break;
+                    }
+                }
+            }
+        }
+        match last_error {
+            Some(e) if bridges.is_empty() => Err(e),
+            _ => Ok(bridges),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use super::*;
+    use crate::DiscoveryMethod;
+
+    /// A strategy that returns the same bridges after a delay and counts how often it was called
+    struct MockDiscovery {
+        bridges: Option<Vec<DiscoveredHueBridge>>,
+        delay: Duration,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl MockDiscovery {
+        fn found(bridges: Vec<DiscoveredHueBridge>) -> Self {
+            Self {
+                bridges: Some(bridges),
+                delay: Duration::ZERO,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }
+        }
+
+        fn failing() -> Self {
+            Self {
+                bridges: None,
+                ..Self::found(Vec::new())
+            }
+        }
+
+        fn delay(mut self, delay: Duration) -> Self {
+            self.delay = delay;
+            self
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Discover for MockDiscovery {
+        async fn discover(&self) -> Result<Vec<DiscoveredHueBridge>, HueError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(self.delay).await;
+            self.bridges
+                .clone()
+                .ok_or_else(|| HueError::DiscoveryError("mock".to_string()))
+        }
+    }
+
+    fn bridge(id: &str, method: DiscoveryMethod) -> DiscoveredHueBridge {
+        DiscoveredHueBridge {
+            id: id.to_string(),
+            url: format!("https://{id}"),
+            discovered_by: vec![method],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn merge_by_id() {
+        let mut bridges = vec![bridge("001788FFFE100491", DiscoveryMethod::Mdns)];
+        merge(
+            &mut bridges,
+            vec![
+                DiscoveredHueBridge {
+                    model_id: Some("BSB002".to_string()),
+                    api_version: Some("1.56.0".to_string()),
+                    ..bridge("001788fffe100491", DiscoveryMethod::Scan)
+                },
+                bridge("001788fffe100491", DiscoveryMethod::Mdns),
+                bridge("001788fffe999999", DiscoveryMethod::Scan),
+            ],
+        );
+        assert_eq!(bridges.len(), 2);
+        assert_eq!(bridges[0].id, "001788FFFE100491");
+        assert_eq!(bridges[0].url, "https://001788FFFE100491");
+        assert_eq!(bridges[0].model_id.as_deref(), Some("BSB002"));
+        assert_eq!(bridges[0].api_version.as_deref(), Some("1.56.0"));
+        assert_eq!(
+            bridges[0].discovered_by,
+            vec![DiscoveryMethod::Mdns, DiscoveryMethod::Scan]
+        );
+        assert_eq!(bridges[1].id, "001788fffe999999");
+    }
+
+    #[tokio::test]
+    async fn fallback_stops_after_min_bridges() {
+        let second = MockDiscovery::found(vec![bridge("b", DiscoveryMethod::Scan)]);
+        let second_calls = second.calls.clone();
+        let bridges = CompositeDiscovery::new()
+            .strategy(MockDiscovery::found(vec![bridge(
+                "a",
+                DiscoveryMethod::Mdns,
+            )]))
+            .strategy(second)
+            .mode(DiscoveryMode::Fallback)
+            .discover()
+            .await
+            .unwrap();
+        assert_eq!(bridges.len(), 1);
+        assert_eq!(second_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn fallback_continues_until_min_bridges() {
+        let bridges = CompositeDiscovery::new()
+            .strategy(MockDiscovery::failing())
+            .strategy(MockDiscovery::found(vec![bridge(
+                "a",
+                DiscoveryMethod::Mdns,
+            )]))
+            .strategy(MockDiscovery::found(vec![
+                bridge("A", DiscoveryMethod::Scan),
+                bridge("b", DiscoveryMethod::Scan),
+            ]))
+            .mode(DiscoveryMode::Fallback)
+            .min_bridges(2)
+            .discover()
+            .await
+            .unwrap();
+        let ids: Vec<_> = bridges.iter().map(|bridge| bridge.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+        assert_eq!(
+            bridges[0].discovered_by,
+            vec![DiscoveryMethod::Mdns, DiscoveryMethod::Scan]
+        );
+    }
+
+    #[tokio::test]
+    async fn error_is_returned_if_no_bridge_was_found() {
+        let result = CompositeDiscovery::new()
+            .strategy(MockDiscovery::failing())
+            .strategy(MockDiscovery::found(Vec::new()))
+            .mode(DiscoveryMode::Fallback)
+            .discover()
+            .await;
+        assert!(matches!(result, Err(HueError::DiscoveryError(_))));
+    }
+
+    #[tokio::test]
+    async fn parallel_returns_without_waiting_for_slow_strategies() {
+        let discovery = CompositeDiscovery::new()
+            .strategy(
+                MockDiscovery::found(vec![bridge("a", DiscoveryMethod::Scan)])
+                    .delay(Duration::from_secs(3600)),
+            )
+            .strategy(
+                MockDiscovery::found(vec![bridge("b", DiscoveryMethod::Mdns)])
+                    .delay(Duration::from_millis(10)),
+            );
+        let bridges = tokio::time::timeout(Duration::from_secs(5), discovery.discover())
+            .await
+            .expect("the slow strategy shouldn't be awaited")
+            .unwrap();
+        assert_eq!(bridges.len(), 1);
+        assert_eq!(bridges[0].id, "b");
+    }
+
+    #[tokio::test]
+    async fn parallel_waits_for_all_strategies() {
+        let bridges = CompositeDiscovery::new()
+            .strategy(MockDiscovery::failing())
+            .strategy(
+                MockDiscovery::found(vec![bridge("a", DiscoveryMethod::Scan)])
+                    .delay(Duration::from_millis(20)),
+            )
+            .strategy(MockDiscovery::found(vec![bridge(
+                "A",
+                DiscoveryMethod::Mdns,
+            )]))
+            .min_bridges(usize::MAX)
+            .discover()
+            .await
+            .unwrap();
+        assert_eq!(bridges.len(), 1);
+        assert_eq!(
+            bridges[0].discovered_by,
+            vec![DiscoveryMethod::Mdns, DiscoveryMethod::Scan]
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use mdns_sd::{RecvTimeoutError, ResolvedService, ServiceDaemon, ServiceEvent};
+
+use crate::{Discover, DiscoveredHueBridge, DiscoveryMethod, HueError};
+
+const SERVICE_TYPE: &str = "_hue._tcp.local.";
+const DEFAULT_BROWSE_DURATION: Duration = Duration::from_secs(5);
+/// How often the browsing thread checks if the discovery was cancelled
+const CANCEL_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Discover hue bridges in the local network using mDNS (`_hue._tcp.local`). No internet access is needed.
+/// The discovery browses for 5 seconds by default.
+/// ```
+/// use std::time::Duration;
+/// use philipshue::{DiscoveredHueBridge, DiscoveryMdns, Discover};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let discovered_hue_bridges: Vec<DiscoveredHueBridge> = DiscoveryMdns::new()
+///         .browse_duration(Duration::from_secs(2))
+///         .discover()
+///         .await
+///         .unwrap();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DiscoveryMdns {
+    browse_duration: Duration,
+}
+
+impl Default for DiscoveryMdns {
+    fn default() -> Self {
+        Self {
+            browse_duration: DEFAULT_BROWSE_DURATION,
+        }
+    }
+}
+
+impl DiscoveryMdns {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The discovery returns all bridges that were found within the `browse_duration`
+    pub fn browse_duration(mut self, browse_duration: Duration) -> Self {
+        self.browse_duration = browse_duration;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Discover for DiscoveryMdns {
+    /// This discover function may throw an `HueError::DiscoveryError` error
+    async fn discover(&self) -> Result<Vec<DiscoveredHueBridge>, HueError> {
+        let browse_duration = self.browse_duration;
+        let daemon = ServiceDaemon::new().map_err(|e| HueError::DiscoveryError(e.to_string()))?;
+        let receiver = daemon
+            .browse(SERVICE_TYPE)
+            .map_err(|e| HueError::DiscoveryError(e.to_string()))?;
+        // the receiver is blocking so it's moved into it's own thread.
+        // If the discovery is cancelled the guard is dropped and the thread stops the daemon
+        let cancelled = CancelGuard(Arc::new(AtomicBool::new(false)));
+        let is_cancelled = cancelled.0.clone();
+        let bridges = tokio::task::spawn_blocking(move || {
+            let deadline = Instant::now() + browse_duration;
+            let mut bridges: Vec<DiscoveredHueBridge> = Vec::new();
+            while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+                if is_cancelled.load(Ordering::Relaxed) {
+                    break;
+                }
+                match receiver.recv_timeout(remaining.min(CANCEL_CHECK_INTERVAL)) {
+                    Ok(ServiceEvent::ServiceResolved(service)) => {
+                        if let Some(bridge) = discovered_bridge(&service) {
+                            if !bridges.iter().any(|known| known.id == bridge.id) {
+                                bridges.push(bridge);
+                            }
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+            let _ = daemon.shutdown();
+            bridges
+        })
+        .await
+        .map_err(|e| HueError::DiscoveryError(e.to_string()));
+        drop(cancelled);
+        bridges
+    }
+}
+
+/// Tells the browsing thread to stop when the discovery is finished or cancelled
+struct CancelGuard(Arc<AtomicBool>);
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+/// The bridge announces it's id and model in the `bridgeid` and `modelid` TXT records
+fn discovered_bridge(service: &ResolvedService) -> Option<DiscoveredHueBridge> {
+    let id = service.get_property_val_str("bridgeid")?;
+    let address = service.get_addresses_v4().into_iter().next()?;
+    Some(DiscoveredHueBridge {
+        id: id.to_string(),
+        url: match service.get_port() {
+            443 => format!("https://{address}"), // tls encrypted
+            port => format!("http://{address}:{port}"),
+        },
+        model_id: service.get_property_val_str("modelid").map(str::to_string),
+        api_version: None,
+        discovered_by: vec![DiscoveryMethod::Mdns],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use mdns_sd::ServiceInfo;
+
+    use super::*;
+
+    fn service(address: &str, port: u16, properties: &[(&str, &str)]) -> ResolvedService {
+        ServiceInfo::new(
+            SERVICE_TYPE,
+            "Philips Hue - 100491",
+            "001788100491.local.",
+            address,
+            port,
+            properties,
+        )
+        .unwrap()
+        .as_resolved_service()
+    }
+
+    #[test]
+    fn bridge_from_txt_records() {
+        let bridge = discovered_bridge(&service(
+            "192.168.178.2",
+            443,
+            &[("bridgeid", "001788fffe100491"), ("modelid", "BSB002")],
+        ))
+        .unwrap();
+        assert_eq!(bridge.id, "001788fffe100491");
+        assert_eq!(bridge.url, "https://192.168.178.2");
+        assert_eq!(bridge.model_id.as_deref(), Some("BSB002"));
+        assert_eq!(bridge.api_version, None);
+        assert_eq!(bridge.discovered_by, vec![DiscoveryMethod::Mdns]);
+    }
+
+    #[test]
+    fn bridge_without_tls() {
+        let bridge = discovered_bridge(&service(
+            "192.168.178.2",
+            80,
+            &[("bridgeid", "001788fffe100491")],
+        ))
+        .unwrap();
+        assert_eq!(bridge.url, "http://192.168.178.2:80");
+        assert_eq!(bridge.model_id, None);
+    }
+
+    #[test]
+    fn bridge_without_id_or_ipv4_address_is_ignored() {
+        assert!(
+            discovered_bridge(&service("192.168.178.2", 443, &[("modelid", "BSB002")])).is_none()
+        );
+        assert!(discovered_bridge(&service(
+            "fe80::1",
+            443,
+            &[("bridgeid", "001788fffe100491")]
+        ))
+        .is_none());
+    }
+
+    #[tokio::test]
+    async fn discovers_a_registered_bridge() {
+        let daemon = ServiceDaemon::new().unwrap();
+        let service = ServiceInfo::new(
+            SERVICE_TYPE,
+            "Philips Hue - 7E57AB",
+            "0017887e57ab.local.",
+            "",
+            443,
+            &[("bridgeid", "0017887ffe7e57ab"), ("modelid", "BSB002")][..],
+        )
+        .unwrap()
+        .enable_addr_auto();
+        daemon.register(service).unwrap();
+
+        let bridges = DiscoveryMdns::new()
+            .browse_duration(Duration::from_secs(3))
+            .discover()
+            .await
+            .unwrap();
+        let _ = daemon.shutdown();
+        // other bridges in the network may be found too
+        let bridge = bridges
+            .iter()
+            .find(|bridge| bridge.id == "0017887ffe7e57ab")
+            .expect("the registered bridge wasn't found");
+        assert!(bridge.url.starts_with("https://"));
+        assert_eq!(bridge.model_id.as_deref(), Some("BSB002"));
+    }
+
+    #[test]
+    fn cancelling_stops_browsing() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let start = Instant::now();
+        let discovery = DiscoveryMdns::new().browse_duration(Duration::from_secs(60));
+        let cancelled = runtime.block_on(async {
+            tokio::time::timeout(Duration::from_millis(200), discovery.discover()).await
+        });
+        assert!(cancelled.is_err());
+        // dropping the runtime waits until the browsing thread is finished
+        drop(runtime);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,210 @@
+use std::{net::Ipv4Addr, time::Duration};
+
+use futures_util::{stream, StreamExt};
+use if_addrs::IfAddr;
+use ipnet::Ipv4Net;
+
+use crate::{config::PublicBridgeConfig, Discover, DiscoveredHueBridge, DiscoveryMethod, HueError};
+
+const DEFAULT_PARALLELISM: usize = 64;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
+const DEFAULT_MAX_HOSTS: u64 = 4096;
+/// Networks of the local interfaces that are larger are reduced to the /24 network around the address of the interface
+const MAX_INTERFACE_PREFIX: u8 = 24;
+
+/// Discover hue bridges by requesting `/api/0/config` from every host of a network.
+/// This works in networks where multicast (mDNS and SSDP) is blocked. Only bridges that support https are found.
+/// If no network is configured the networks of the local interfaces are scanned (at most a /24 network per interface).
+/// At most 4096 hosts are scanned by default, larger networks are rejected with an `HueError::DiscoveryError` error.
+/// ```
+/// use std::time::Duration;
+/// use philipshue::{DiscoveredHueBridge, DiscoveryScan, Discover};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let discovered_hue_bridges: Vec<DiscoveredHueBridge> = DiscoveryScan::new()
+///         .network("192.168.178.0/24")
+///         .unwrap()
+///         .parallelism(32)
+///         .timeout(Duration::from_secs(1))
+///         .discover()
+///         .await
+///         .unwrap();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DiscoveryScan {
+    networks: Vec<Ipv4Net>,
+    parallelism: usize,
+    timeout: Duration,
+    max_hosts: u64,
+}
+
+impl Default for DiscoveryScan {
+    fn default() -> Self {
+        Self {
+            networks: Vec::new(),
+            parallelism: DEFAULT_PARALLELISM,
+            timeout: DEFAULT_TIMEOUT,
+            max_hosts: DEFAULT_MAX_HOSTS,
+        }
+    }
+}
+
+impl DiscoveryScan {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a network in the CIDR notation like `192.168.178.0/24` that should be scanned
+    pub fn network(mut self, cidr: &str) -> Result<Self, HueError> {
+        self.networks.push(
+            cidr.parse()
+                .map_err(|_| HueError::DiscoveryError(format!("invalid network: {cidr}")))?,
+        );
+        Ok(self)
+    }
+
+    /// The number of hosts that are requested at the same time, `64` by default
+    pub fn parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    /// The timeout of the request to a single host, `2` seconds by default
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The maximum number of hosts of all networks together, `4096` by default
+    pub fn max_hosts(mut self, max_hosts: u64) -> Self {
+        self.max_hosts = max_hosts;
+        self
+    }
+}
+
+/// The number of hosts `Ipv4Net::hosts` returns, the network and broadcast address are excluded if the prefix is shorter than 31
+fn host_count(network: &Ipv4Net) -> u64 {
+    let addresses = 1u64 << (32 - network.prefix_len());
+    match network.prefix_len() {
+        31 | 32 => addresses,
+        _ => addresses - 2,
+    }
+}
+
+/// The ipv4 networks of all local interfaces except the loopback interface
+fn interface_networks() -> Result<Vec<Ipv4Net>, HueError> {
+    Ok(if_addrs::get_if_addrs()
+        .map_err(|e| HueError::DiscoveryError(e.to_string()))?
+        .into_iter()
+        .filter_map(|interface| match interface.addr {
+            IfAddr::V4(address) if !address.is_loopback() && !address.is_link_local() => {
+                Ipv4Net::new(address.ip, address.prefixlen.max(MAX_INTERFACE_PREFIX))
+                    .ok()
+                    .map(|network| network.trunc())
+            }
+            _ => None,
+        })
+        .collect())
+}
+
+/// A host is a hue bridge if it replies with a `PublicBridgeConfig`
+async fn probe(client: &reqwest::Client, host: Ipv4Addr) -> Option<DiscoveredHueBridge> {
+    let url = format!("https://{host}");
+    let config = client
+        .get(format!("{url}/api/0/config"))
+        .send()
+        .await
+        .ok()?
+        .error_for_status()
+        .ok()?
+        .json::<PublicBridgeConfig>()
+        .await
+        .ok()?;
+    Some(DiscoveredHueBridge {
+        id: config.bridge_id,
+        url,
+        model_id: Some(config.model_id),
+        api_version: Some(config.api_version),
+        discovered_by: vec![DiscoveryMethod::Scan],
+    })
+}
+
+#[async_trait::async_trait]
+impl Discover for DiscoveryScan {
+    /// Scan the networks, this discover function may throw an `HueError::DiscoveryError` error
+    async fn discover(&self) -> Result<Vec<DiscoveredHueBridge>, HueError> {
+        let networks = match self.networks.is_empty() {
+            true => interface_networks()?,
+            false => self.networks.clone(),
+        };
+        let host_count: u64 = networks.iter().map(host_count).sum();
+        if host_count > self.max_hosts {
+            return Err(HueError::DiscoveryError(format!(
+                "the networks contain {host_count} hosts, at most {} hosts are scanned",
+                self.max_hosts
+            )));
+        }
+        let client = reqwest::ClientBuilder::new()
+            .danger_accept_invalid_certs(true)
+            .timeout(self.timeout)
+            .build()?;
+        let mut hosts = networks
+            .iter()
+            .flat_map(|network| network.hosts())
+            .collect::<Vec<_>>();
+        hosts.sort();
+        hosts.dedup();
+        Ok(stream::iter(hosts)
+            .map(|host| probe(&client, host))
+            .buffer_unordered(self.parallelism)
+            .filter_map(|bridge| async { bridge })
+            .collect()
+            .await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_count_matches_hosts() {
+        for cidr in [
+            "192.168.178.0/24",
+            "10.0.0.0/22",
+            "10.0.0.4/30",
+            "10.0.0.4/31",
+            "10.0.0.4/32",
+        ] {
+            let network: Ipv4Net = cidr.parse().unwrap();
+            assert_eq!(
+                host_count(&network),
+                network.hosts().count() as u64,
+                "{cidr}"
+            );
+        }
+        assert_eq!(host_count(&"0.0.0.0/0".parse().unwrap()), (1 << 32) - 2);
+    }
+
+    #[tokio::test]
+    async fn too_many_hosts_are_rejected() {
+        let scan = DiscoveryScan::new().network("10.0.0.0/8").unwrap();
+        assert!(matches!(
+            scan.discover().await,
+            Err(HueError::DiscoveryError(_))
+        ));
+
+        let scan = DiscoveryScan::new()
+            .network("192.168.178.0/24")
+            .unwrap()
+            .network("192.168.179.0/24")
+            .unwrap()
+            .max_hosts(300);
+        assert!(matches!(
+            scan.discover().await,
+            Err(HueError::DiscoveryError(_))
+        ));
+    }
+}
@@ -0,0 +1,291 @@
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    time::{Duration, Instant},
+};
+
+use tokio::net::UdpSocket;
+
+use crate::{Discover, DiscoveredHueBridge, DiscoveryMethod, HueError};
+
+const SSDP_ADDRESS: (Ipv4Addr, u16) = (Ipv4Addr::new(239, 255, 255, 250), 1900);
+const DEFAULT_SEARCH_DURATION: Duration = Duration::from_secs(4);
+/// The first generation bridge doesn't support https
+const FIRST_GENERATION_MODEL_NUMBER: &str = "929000226503";
+
+/// Discover hue bridges in the local network using SSDP (UPnP). This works with older bridges and networks without mDNS.
+/// The discovery searches for 4 seconds by default.
+/// ```
+/// use std::time::Duration;
+/// use philipshue::{DiscoveredHueBridge, DiscoverySsdp, Discover};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let discovered_hue_bridges: Vec<DiscoveredHueBridge> = DiscoverySsdp::new()
+///         .search_duration(Duration::from_secs(2))
+///         .discover()
+///         .await
+///         .unwrap();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DiscoverySsdp {
+    search_duration: Duration,
+}
+
+impl Default for DiscoverySsdp {
+    fn default() -> Self {
+        Self {
+            search_duration: DEFAULT_SEARCH_DURATION,
+        }
+    }
+}
+
+/// A response to the `M-SEARCH` request that was sent by a hue bridge
+struct SsdpResponse {
+    bridge_id: String,
+    location: String,
+}
+
+impl DiscoverySsdp {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The discovery returns all bridges that replied within the `search_duration`
+    pub fn search_duration(mut self, search_duration: Duration) -> Self {
+        self.search_duration = search_duration;
+        self
+    }
+
+    async fn search(search_duration: Duration) -> std::io::Result<Vec<SsdpResponse>> {
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+        let request = format!(
+            "M-SEARCH * HTTP/1.1\r\nHOST: 239.255.255.250:1900\r\nMAN: \"ssdp:discover\"\r\nMX: {}\r\nST: ssdp:all\r\n\r\n",
+            search_duration.as_secs().clamp(1, 5)
+        );
+        socket
+            .send_to(request.as_bytes(), SocketAddr::from(SSDP_ADDRESS))
+            .await?;
+
+        let deadline = Instant::now() + search_duration;
+        let mut responses: Vec<SsdpResponse> = Vec::new();
+        let mut buffer = [0; 2048];
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            let Ok(received) = tokio::time::timeout(remaining, socket.recv_from(&mut buffer)).await
+            else {
+                break;
+            };
+            let (length, _) = received?;
+            if let Some(response) = parse_response(&String::from_utf8_lossy(&buffer[..length])) {
+                if !responses
+                    .iter()
+                    .any(|known| known.bridge_id == response.bridge_id)
+                {
+                    responses.push(response);
+                }
+            }
+        }
+        Ok(responses)
+    }
+}
+
+/// Only responses with a `hue-bridgeid` header and an `IpBridge` server are sent by hue bridges
+fn parse_response(response: &str) -> Option<SsdpResponse> {
+    let mut bridge_id = None;
+    let mut location = None;
+    let mut is_bridge = false;
+    for line in response.lines() {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match name.trim().to_lowercase().as_str() {
+            "hue-bridgeid" => bridge_id = Some(value.to_string()),
+            "location" => location = Some(value.to_string()),
+            "server" => is_bridge = value.contains("IpBridge"),
+            _ => {}
+        }
+    }
+    if !is_bridge {
+        return None;
+    }
+    Some(SsdpResponse {
+        bridge_id: bridge_id?,
+        location: location?,
+    })
+}
+
+/// Get the text of the first `<tag>` element of the xml
+fn xml_value<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+    let start = xml.find(&format!("<{tag}>"))? + tag.len() + 2;
+    let end = start + xml[start..].find(&format!("</{tag}>"))?;
+    Some(xml[start..end].trim())
+}
+
+fn discovered_bridge(response: SsdpResponse, description: &str) -> Option<DiscoveredHueBridge> {
+    if !xml_value(description, "modelName")?
+        .to_lowercase()
+        .contains("philips hue bridge")
+    {
+        return None;
+    }
+    let model_id = xml_value(description, "modelNumber").map(str::to_string);
+    let location = reqwest::Url::parse(&response.location).ok()?;
+    let host = location.host_str()?;
+    Some(DiscoveredHueBridge {
+        id: response.bridge_id,
+        url: match model_id.as_deref() {
+            Some(FIRST_GENERATION_MODEL_NUMBER) => {
+                format!("http://{host}:{}", location.port_or_known_default()?)
+            }
+            _ => format!("https://{host}"), // tls encrypted
+        },
+        model_id,
+        api_version: None,
+        discovered_by: vec![DiscoveryMethod::Ssdp],
+    })
+}
+
+#[async_trait::async_trait]
+impl Discover for DiscoverySsdp {
+    /// Send an `M-SEARCH` request and collect the responses, this discover function may throw an `HueError::DiscoveryError` or `HueError::RequestError` error
+    async fn discover(&self) -> Result<Vec<DiscoveredHueBridge>, HueError> {
+        let responses = Self::search(self.search_duration)
+            .await
+            .map_err(|e| HueError::DiscoveryError(e.to_string()))?;
+        let client = reqwest::ClientBuilder::new()
+            .timeout(Duration::from_secs(5))
+            .build()?;
+        let mut bridges = Vec::new();
+        for response in responses {
+            // the description is fetched to make sure the device is really a hue bridge
+            let Ok(description) = client.get(&response.location).send().await else {
+                continue;
+            };
+            let Ok(description) = description.text().await else {
+                continue;
+            };
+            if let Some(bridge) = discovered_bridge(response, &description) {
+                bridges.push(bridge);
+            }
+        }
+        Ok(bridges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESPONSE: &str = "HTTP/1.1 200 OK\r\n\
+        HOST: 239.255.255.250:1900\r\n\
+        EXT:\r\n\
+        CACHE-CONTROL: max-age=100\r\n\
+        LOCATION: http://192.168.178.2:80/description.xml\r\n\
+        SERVER: Linux/3.14.0 UPnP/1.0 IpBridge/1.56.0\r\n\
+        hue-bridgeid: 001788FFFE100491\r\n\
+        ST: upnp:rootdevice\r\n\
+        USN: uuid:2f402f80-da50-11e1-9b23-001788100491::upnp:rootdevice\r\n\r\n";
+
+    fn description(model_name: &str, model_number: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" ?>
+<root xmlns="urn:schemas-upnp-org:device-1-0">
+<specVersion><major>1</major><minor>0</minor></specVersion>
+<URLBase>http://192.168.178.2:80/</URLBase>
+<device>
+<deviceType>urn:schemas-upnp-org:device:Basic:1</deviceType>
+<friendlyName>Philips hue (192.168.178.2)</friendlyName>
+<manufacturer>Signify</manufacturer>
+<modelDescription>Philips hue Personal Wireless Lighting</modelDescription>
+<modelName>{model_name}</modelName>
+<modelNumber>{model_number}</modelNumber>
+<serialNumber>001788100491</serialNumber>
+<UDN>uuid:2f402f80-da50-11e1-9b23-001788100491</UDN>
+</device>
+</root>"#
+        )
+    }
+
+    fn response() -> SsdpResponse {
+        parse_response(RESPONSE).unwrap()
+    }
+
+    #[test]
+    fn response_of_a_bridge() {
+        let response = response();
+        assert_eq!(response.bridge_id, "001788FFFE100491");
+        assert_eq!(response.location, "http://192.168.178.2:80/description.xml");
+    }
+
+    #[test]
+    fn response_without_bridge_id_or_of_another_device_is_ignored() {
+        let without_id = RESPONSE.replace("hue-bridgeid: 001788FFFE100491\r\n", "");
+        assert!(parse_response(&without_id).is_none());
+        let other_device = RESPONSE.replace("IpBridge/1.56.0", "MiniUPnPd/2.1");
+        assert!(parse_response(&other_device).is_none());
+    }
+
+    #[test]
+    fn values_of_the_description() {
+        let description = description("Philips hue bridge 2015", "BSB002");
+        assert_eq!(xml_value(&description, "modelNumber"), Some("BSB002"));
+        assert_eq!(
+            xml_value(&description, "serialNumber"),
+            Some("001788100491")
+        );
+        assert_eq!(xml_value(&description, "presentationURL"), None);
+    }
+
+    #[test]
+    fn bridge_from_description() {
+        let bridge = discovered_bridge(
+            response(),
+            &description("Philips hue bridge 2015", "BSB002"),
+        )
+        .unwrap();
+        assert_eq!(bridge.id, "001788FFFE100491");
+        assert_eq!(bridge.url, "https://192.168.178.2");
+        assert_eq!(bridge.model_id.as_deref(), Some("BSB002"));
+        assert_eq!(bridge.discovered_by, vec![DiscoveryMethod::Ssdp]);
+    }
+
+    #[test]
+    fn first_generation_bridge_without_tls() {
+        let bridge = discovered_bridge(
+            response(),
+            &description("Philips hue bridge 2012", FIRST_GENERATION_MODEL_NUMBER),
+        )
+        .unwrap();
+        assert_eq!(bridge.url, "http://192.168.178.2:80");
+    }
+
+    #[test]
+    fn other_devices_are_ignored() {
+        assert!(discovered_bridge(response(), &description("FRITZ!Box 7590", "7590")).is_none());
+    }
+
+    #[test]
+    fn bridge_id_is_merged_with_other_methods() {
+        // the bridge sends the id in upper case, mDNS and the api use lower case
+        let bridge = discovered_bridge(
+            response(),
+            &description("Philips hue bridge 2015", "BSB002"),
+        )
+        .unwrap();
+        let mut bridges = vec![DiscoveredHueBridge {
+            id: "001788fffe100491".to_string(),
+            url: "https://192.168.178.2".to_string(),
+            model_id: None,
+            api_version: None,
+            discovered_by: vec![DiscoveryMethod::Mdns],
+        }];
+        crate::discover::composite_discovery::merge(&mut bridges, vec![bridge]);
+        assert_eq!(bridges.len(), 1);
+        assert_eq!(bridges[0].model_id.as_deref(), Some("BSB002"));
+        assert_eq!(
+            bridges[0].discovered_by,
+            vec![DiscoveryMethod::Mdns, DiscoveryMethod::Ssdp]
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use crate::{Discover, DiscoveredHueBridge, HueError};
+use crate::{Discover, DiscoveredHueBridge, DiscoveryMethod, HueError};
 
 const DISCOVER_ADDRESS: &str = "https://discovery.meethue.com";
 
@@ -20,6 +20,9 @@ impl From<DiscoveryUrlRespones> for DiscoveredHueBridge {
                 443 => format!("https://{}", value.internal_ip_address), // tls encrypted
                 _ => format!("http://{}:{}", value.internal_ip_address, value.port),
             },
+            model_id: None,
+            api_version: None,
+            discovered_by: vec![DiscoveryMethod::Cloud],
         }
     }
 }
@@ -31,7 +34,7 @@ impl From<DiscoveryUrlRespones> for DiscoveredHueBridge {
 ///
 /// #[tokio::main]
 /// async fn main() {
-///     let discovered_hue_bridges: Vec<DiscoveredHueBridge> = DiscoveryUrl::discover().await.unwrap();
+///     let discovered_hue_bridges: Vec<DiscoveredHueBridge> = DiscoveryUrl.discover().await.unwrap();
 /// }
 /// ```
 pub struct DiscoveryUrl;
@@ -39,7 +42,7 @@ pub struct DiscoveryUrl;
 #[async_trait::async_trait]
 impl Discover for DiscoveryUrl {
     /// This discover function may throw an `HueError::RequestError` error.
-    async fn discover() -> Result<Vec<DiscoveredHueBridge>, HueError> {
+    async fn discover(&self) -> Result<Vec<DiscoveredHueBridge>, HueError> {
         Ok(reqwest::get(DISCOVER_ADDRESS)
             .await?
             .error_for_status()?
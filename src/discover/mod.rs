@@ -1,7 +1,15 @@
 use crate::error::HueError;
 
+mod composite_discovery;
+mod discovery_mdns;
+mod discovery_scan;
+mod discovery_ssdp;
 mod discovery_url;
 
+pub use composite_discovery::{CompositeDiscovery, DiscoveryMode};
+pub use discovery_mdns::DiscoveryMdns;
+pub use discovery_scan::DiscoveryScan;
+pub use discovery_ssdp::DiscoverySsdp;
 pub use discovery_url::DiscoveryUrl;
 
 /// This struct identifies a discovered hue bridge.
@@ -11,25 +19,50 @@ pub use discovery_url::DiscoveryUrl;
 ///
 /// let discovered_hue_bridge = DiscoveredHueBridge {
 ///     id: "id".to_string(),
-///     url: "url".to_string()
+///     url: "url".to_string(),
+///     ..Default::default()
 /// };
 /// let hue_bridge: HueBridge = discovered_hue_bridge.into();
 /// ```
-#[derive(Clone)]
+#[derive(Clone, Debug, Default)]
 pub struct DiscoveredHueBridge {
     pub id: String,
     pub url: String,
+    /// The model of the bridge like `BSB002`, not every discovery method knows it
+    pub model_id: Option<String>,
+    /// The api version of the bridge like `1.56.0`, not every discovery method knows it
+    pub api_version: Option<String>,
+    /// The discovery methods that found the bridge
+    pub discovered_by: Vec<DiscoveryMethod>,
 }
 
-/// If you don't know the bridge url of your `HueBridge` it's possible to discover it. There are several methods to discover a hue bridge. Currently just one is implemented:
+/// The method a `DiscoveredHueBridge` was found with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiscoveryMethod {
+    /// `DiscoveryUrl`
+    Cloud,
+    /// `DiscoveryMdns`
+    Mdns,
+    /// `DiscoverySsdp`
+    Ssdp,
+    /// `DiscoveryScan`
+    Scan,
+}
+
+/// If you don't know the bridge url of your `HueBridge` it's possible to discover it. There are several methods to discover a hue bridge:
 /// - `DiscoveryUrl`
+/// - `DiscoveryMdns`
+/// - `DiscoverySsdp`
+/// - `DiscoveryScan`
+///
+/// Use `CompositeDiscovery` to combine them.
 #[async_trait::async_trait]
-pub trait Discover {
+pub trait Discover: Send + Sync {
     /// Discover multiple hue bridges
-    async fn discover() -> Result<Vec<DiscoveredHueBridge>, HueError>;
+    async fn discover(&self) -> Result<Vec<DiscoveredHueBridge>, HueError>;
     /// Discover just one `HueBridge` and throw an `HueError::NoBridgeFound` error if no bridge was found
-    async fn discover_one() -> Result<DiscoveredHueBridge, HueError> {
-        Self::discover()
+    async fn discover_one(&self) -> Result<DiscoveredHueBridge, HueError> {
+        self.discover()
             .await?
             .first()
             .cloned()
@@ -0,0 +1,208 @@
+use crate::error::HueError;
+
+/// The bridge accepts at most 20 channels per frame
+pub const MAX_CHANNELS: usize = 20;
+/// The UUID of the entertainment configuration is sent in it's text form
+const CONFIGURATION_ID_LENGTH: usize = 36;
+
+/// The color of one channel of an entertainment configuration
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChannelColor {
+    /// 16 bit rgb values
+    Rgb { red: u16, green: u16, blue: u16 },
+    /// The x and y coordinates of a color in the CIE color space and the brightness (everything between `0` and `1`)
+    Xy { x: f32, y: f32, brightness: f32 },
+}
+
+impl ChannelColor {
+    /// An 8 bit rgb color, it's scaled to the 16 bit the bridge uses
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::Rgb {
+            red: u16::from(red) * 257,
+            green: u16::from(green) * 257,
+            blue: u16::from(blue) * 257,
+        }
+    }
+
+    pub fn xy(x: f32, y: f32, brightness: f32) -> Self {
+        Self::Xy { x, y, brightness }
+    }
+
+    fn color_space(&self) -> u8 {
+        match self {
+            Self::Rgb { .. } => 0x00,
+            Self::Xy { .. } => 0x01,
+        }
+    }
+
+    fn values(&self) -> [u16; 3] {
+        let scale = |value: f32| (value.clamp(0.0, 1.0) * f32::from(u16::MAX)).round() as u16;
+        match *self {
+            Self::Rgb { red, green, blue } => [red, green, blue],
+            Self::Xy { x, y, brightness } => [scale(x), scale(y), scale(brightness)],
+        }
+    }
+}
+
+/// The colors of the channels of an entertainment configuration that are sent at once
+/// ```
+/// use philipshue::entertainment::{ChannelColor, Frame};
+///
+/// let frame = Frame::new()
+///     .channel(0, ChannelColor::rgb(255, 0, 0)) // the first channel is red
+///     .channel(1, ChannelColor::rgb(0, 0, 255)); // the second channel is blue
+/// ```
+/// All channels of a frame must use the same kind of `ChannelColor` and a frame contains at most `MAX_CHANNELS` channels.
+#[derive(Clone, Debug, Default)]
+pub struct Frame {
+    channels: Vec<(u8, ChannelColor)>,
+}
+
+impl Frame {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no channel was set
+    pub fn is_empty(&self) -> bool {
+        self.channels.is_empty()
+    }
+
+    /// Set the color of the channel, the channel ids are part of the entertainment configuration
+    pub fn channel(mut self, channel_id: u8, color: ChannelColor) -> Self {
+        match self.channels.iter_mut().find(|(id, _)| *id == channel_id) {
+            Some((_, known)) => *known = color,
+            None => self.channels.push((channel_id, color)),
+        }
+        self
+    }
+
+    /// Encode the frame as HueStream v2 message, the bridge ignores the sequence number so it's always `0`
+    pub(crate) fn encode(&self, configuration_id: &str) -> Result<Vec<u8>, HueError> {
+        if self.channels.len() > MAX_CHANNELS {
+            return Err(HueError::InvalidFrame(format!(
+                "a frame can contain at most {MAX_CHANNELS} channels"
+            )));
+        }
+        if configuration_id.len() != CONFIGURATION_ID_LENGTH {
+            return Err(HueError::InvalidFrame(format!(
+                "the entertainment configuration id {configuration_id} isn't a UUID"
+            )));
+        }
+        let color_space = self
+            .channels
+            .first()
+            .map_or(0x00, |(_, color)| color.color_space());
+        if self
+            .channels
+            .iter()
+            .any(|(_, color)| color.color_space() != color_space)
+        {
+            return Err(HueError::InvalidFrame(
+                "all channels must use the same color space".to_string(),
+            ));
+        }
+
+        let mut message =
+            Vec::with_capacity(16 + CONFIGURATION_ID_LENGTH + 7 * self.channels.len());
+        message.extend_from_slice(b"HueStream");
+        message.extend_from_slice(&[0x02, 0x00]); // version 2.0
+        message.push(0x00); // sequence number
+        message.extend_from_slice(&[0x00, 0x00]); // reserved
+        message.push(color_space);
+        message.push(0x00); // reserved
+        message.extend_from_slice(configuration_id.as_bytes());
+        for (channel_id, color) in &self.channels {
+            message.push(*channel_id);
+            for value in color.values() {
+                message.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIGURATION_ID: &str = "1e3f4c2a-5b6d-4e7f-8a9b-0c1d2e3f4a5b";
+
+    #[test]
+    fn header_layout() {
+        let message = Frame::new()
+            .channel(0, ChannelColor::rgb(255, 0, 0))
+            .channel(
+                7,
+                ChannelColor::Rgb {
+                    red: 0x0102,
+                    green: 0x0304,
+                    blue: 0x0506,
+                },
+            )
+            .encode(CONFIGURATION_ID)
+            .unwrap();
+        let mut expected = b"HueStream".to_vec();
+        expected.extend_from_slice(&[0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        expected.extend_from_slice(CONFIGURATION_ID.as_bytes());
+        expected.extend_from_slice(&[0x00, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00]);
+        expected.extend_from_slice(&[0x07, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn xy_color_space() {
+        let message = Frame::new()
+            .channel(2, ChannelColor::xy(0.0, 1.0, 0.5))
+            .encode(CONFIGURATION_ID)
+            .unwrap();
+        assert_eq!(message[14], 0x01);
+        assert_eq!(
+            message[16 + CONFIGURATION_ID_LENGTH..],
+            [0x02, 0x00, 0x00, 0xff, 0xff, 0x80, 0x00]
+        );
+    }
+
+    #[test]
+    fn channel_is_replaced() {
+        let frame = Frame::new()
+            .channel(1, ChannelColor::rgb(255, 0, 0))
+            .channel(1, ChannelColor::rgb(0, 255, 0));
+        let message = frame.encode(CONFIGURATION_ID).unwrap();
+        assert_eq!(message.len(), 16 + CONFIGURATION_ID_LENGTH + 7);
+        assert_eq!(
+            message[16 + CONFIGURATION_ID_LENGTH..],
+            [0x01, 0x00, 0x00, 0xff, 0xff, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn channel_limit() {
+        let frame = (0..MAX_CHANNELS as u8).fold(Frame::new(), |frame, channel_id| {
+            frame.channel(channel_id, ChannelColor::rgb(0, 0, 0))
+        });
+        assert!(frame.encode(CONFIGURATION_ID).is_ok());
+        let frame = frame.channel(MAX_CHANNELS as u8, ChannelColor::rgb(0, 0, 0));
+        assert!(matches!(
+            frame.encode(CONFIGURATION_ID),
+            Err(HueError::InvalidFrame(_))
+        ));
+    }
+
+    #[test]
+    fn mixed_color_spaces() {
+        let frame = Frame::new()
+            .channel(0, ChannelColor::rgb(255, 0, 0))
+            .channel(1, ChannelColor::xy(0.3, 0.3, 1.0));
+        assert!(matches!(
+            frame.encode(CONFIGURATION_ID),
+            Err(HueError::InvalidFrame(_))
+        ));
+    }
+
+    #[test]
+    fn configuration_id_must_be_a_uuid() {
+        let frame = Frame::new().channel(0, ChannelColor::rgb(255, 0, 0));
+        assert!(matches!(frame.encode("1"), Err(HueError::InvalidFrame(_))));
+    }
+}
@@ -0,0 +1,237 @@
+//! Stream colors to the lights of an entertainment configuration with the entertainment api (HueStream v2 over DTLS).
+//! This module needs the `entertainment` feature and a `Hue` with a client key (`DeviceType::generate_client_key`).
+
+use std::{net::Ipv4Addr, sync::Arc, time::Duration};
+
+use tokio::{net::UdpSocket, sync::watch, task::JoinHandle};
+use webrtc_dtls::{cipher_suite::CipherSuiteId, config::Config, conn::DTLSConn};
+
+use crate::{error::HueError, v2::ResourceType, Hue};
+
+mod frame;
+
+pub use frame::*;
+
+const DEFAULT_PORT: u16 = 2100;
+const DEFAULT_FRAME_INTERVAL: Duration = Duration::from_millis(20);
+const DEFAULT_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Options for `Hue::start_entertainment`
+#[derive(Clone, Debug)]
+pub struct EntertainmentOptions {
+    port: u16,
+    frame_interval: Duration,
+    handshake_timeout: Duration,
+}
+
+impl Default for EntertainmentOptions {
+    fn default() -> Self {
+        Self {
+            port: DEFAULT_PORT,
+            frame_interval: DEFAULT_FRAME_INTERVAL,
+            handshake_timeout: DEFAULT_HANDSHAKE_TIMEOUT,
+        }
+    }
+}
+
+impl EntertainmentOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The udp port of the bridge, `2100` by default
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// The current frame is sent every `frame_interval`, `20ms` (50 frames per second) by default
+    pub fn frame_interval(mut self, frame_interval: Duration) -> Self {
+        self.frame_interval = frame_interval;
+        self
+    }
+
+    /// `5s` by default
+    pub fn handshake_timeout(mut self, handshake_timeout: Duration) -> Self {
+        self.handshake_timeout = handshake_timeout;
+        self
+    }
+}
+
+/// A running entertainment stream, created by `Hue::start_entertainment`.
+/// The current frame is repeated at a steady rate until it's replaced with `EntertainmentStream::set_frame`.
+/// Call `EntertainmentStream::stop` when you are done. Dropping the stream only stops sending frames, the bridge stops the entertainment configuration 10 seconds after the last frame
+pub struct EntertainmentStream {
+    hue: Hue,
+    configuration_id: String,
+    connection: Arc<DTLSConn>,
+    frame: watch::Sender<Option<Vec<u8>>>,
+    sender: JoinHandle<Result<(), HueError>>,
+}
+
+/// The PSK is the client key in it's hex form, an odd number of digits is invalid because the last byte is incomplete
+fn decode_client_key(client_key: &str) -> Result<Vec<u8>, HueError> {
+    let invalid = || HueError::StreamError("the client key isn't valid hex".to_string());
+    (0..client_key.len())
+        .step_by(2)
+        .map(|i| {
+            client_key
+                .get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(invalid)
+        })
+        .collect()
+}
+
+impl Hue {
+    /// Start the entertainment configuration and open the DTLS connection to the bridge
+    /// ```
+    /// use philipshue::{Hue, HueBridge, entertainment::{ChannelColor, EntertainmentOptions, Frame}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string())
+    ///         .with_client_key("client key".to_string());
+    ///     let stream = hue
+    ///         .start_entertainment("1e3f4c2a-5b6d-4e7f-8a9b-0c1d2e3f4a5b", EntertainmentOptions::new())
+    ///         .await
+    ///         .unwrap();
+    ///     stream.set_frame(&Frame::new().channel(0, ChannelColor::rgb(255, 0, 0))).unwrap();
+    ///     tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+    ///     stream.stop().await.unwrap();
+    /// }
+    /// ```
+    pub async fn start_entertainment(
+        &self,
+        configuration_id: &str,
+        options: EntertainmentOptions,
+    ) -> Result<EntertainmentStream, HueError> {
+        let psk = decode_client_key(self.client_key.as_deref().ok_or_else(|| {
+            HueError::StreamError("a client key is required for streaming".to_string())
+        })?)?;
+        let host = reqwest::Url::parse(&self.bridge.bridge_url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .ok_or_else(|| HueError::StreamError("the bridge url has no host".to_string()))?;
+
+        self.set_entertainment_action(configuration_id, "start")
+            .await?;
+        let connection = match self.connect_stream(&host, psk, &options).await {
+            Ok(connection) => Arc::new(connection),
+            Err(e) => {
+                let _ = self
+                    .set_entertainment_action(configuration_id, "stop")
+                    .await;
+                return Err(e);
+            }
+        };
+
+        let (frame, frames) = watch::channel::<Option<Vec<u8>>>(None);
+        let sender = tokio::spawn({
+            let connection = connection.clone();
+            async move {
+                let mut interval = tokio::time::interval(options.frame_interval);
+                interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+                loop {
+                    interval.tick().await;
+                    let message = frames.borrow().clone();
+                    if let Some(message) = message {
+                        connection
+                            .write(&message, None)
+                            .await
+                            .map_err(|e| HueError::StreamError(e.to_string()))?;
+                    }
+                }
+            }
+        });
+        Ok(EntertainmentStream {
+            hue: self.clone(),
+            configuration_id: configuration_id.to_string(),
+            connection,
+            frame,
+            sender,
+        })
+    }
+
+    async fn set_entertainment_action(
+        &self,
+        configuration_id: &str,
+        action: &str,
+    ) -> Result<(), HueError> {
+        self.v2()
+            .put(
+                ResourceType::EntertainmentConfiguration,
+                configuration_id,
+                &serde_json::json!({ "action": action }),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// The username is the PSK identity
+    async fn connect_stream(
+        &self,
+        host: &str,
+        psk: Vec<u8>,
+        options: &EntertainmentOptions,
+    ) -> Result<DTLSConn, HueError> {
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
+            .await
+            .map_err(|e| HueError::StreamError(e.to_string()))?;
+        socket
+            .connect((host, options.port))
+            .await
+            .map_err(|e| HueError::StreamError(e.to_string()))?;
+        let config = Config {
+            psk: Some(Arc::new(move |_| Ok(psk.clone()))),
+            psk_identity_hint: Some(self.username.as_bytes().to_vec()),
+            cipher_suites: vec![CipherSuiteId::Tls_Psk_With_Aes_128_Gcm_Sha256],
+            ..Default::default()
+        };
+        tokio::time::timeout(
+            options.handshake_timeout,
+            DTLSConn::new(Arc::new(socket), config, true, None),
+        )
+        .await
+        .map_err(|_| HueError::StreamError("the DTLS handshake timed out".to_string()))?
+        .map_err(|e| HueError::StreamError(e.to_string()))
+    }
+}
+
+impl EntertainmentStream {
+    /// Replace the frame that is sent to the bridge
+    pub fn set_frame(&self, frame: &Frame) -> Result<(), HueError> {
+        self.frame
+            .send_replace(Some(frame.encode(&self.configuration_id)?));
+        Ok(())
+    }
+
+    /// Returns `true` if the stream stopped because a frame couldn't be sent, `EntertainmentStream::stop` returns the error
+    pub fn is_finished(&self) -> bool {
+        self.sender.is_finished()
+    }
+
+    /// Stop sending frames, close the connection and stop the entertainment configuration
+    pub async fn stop(mut self) -> Result<(), HueError> {
+        let result = if self.sender.is_finished() {
+            (&mut self.sender)
+                .await
+                .map_err(|e| HueError::StreamError(e.to_string()))
+                .and_then(|result| result)
+        } else {
+            self.sender.abort();
+            Ok(())
+        };
+        let _ = self.connection.close().await;
+        self.hue
+            .set_entertainment_action(&self.configuration_id, "stop")
+            .await?;
+        result
+    }
+}
+
+impl Drop for EntertainmentStream {
+    fn drop(&mut self) {
+        self.sender.abort();
+    }
+}
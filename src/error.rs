@@ -13,23 +13,91 @@ pub enum HueError {
     /// You tried to discover a bridge but no bridge was found
     #[error("no philipshue bridge could be found")]
     NoBridgeFound,
+    /// The discovery failed for another reason than a request error (for example because the network interfaces couldn't be used)
+    #[error("error while discovering a philipshue bridge: {0}")]
+    DiscoveryError(String),
     /// The server should reply with an `success` or an `error` object but none of both were found.
     #[error("the server didn't reply with success or error")]
     NoData,
+    /// A time pattern couldn't be parsed because it doesn't match any of the time patterns the bridge supports
+    #[error("invalid time pattern: {0}")]
+    InvalidTimePattern(String),
+    /// The rule wasn't sent to the bridge because the bridge wouldn't accept it (for example because it has too many conditions)
+    #[error("invalid rule: {0}")]
+    InvalidRule(String),
+    /// The bridge rejected some attributes of a state change, every rejected attribute has it's own `ApiError`
+    #[error("the philipshue bridge rejected {} attributes of the state change", .0.len())]
+    PartialStateChange(Vec<ApiError>),
+    /// The bridge rejected every attribute of a state change with more than one attribute, every rejected attribute has it's own `ApiError`
+    #[error("the philipshue bridge rejected all {} attributes of the state change", .0.len())]
+    StateChangeRejected(Vec<ApiError>),
+    /// `HueBridge::pair` gave up because the link button wasn't pressed in time
+    #[error("the link button of the philipshue bridge wasn't pressed in time")]
+    PairingTimeout,
+    /// `Hue::wait_for_new_lights` or `Hue::wait_for_new_sensors` gave up because the search wasn't finished in time
+    #[error("the search of the philipshue bridge wasn't finished in time")]
+    SearchTimeout,
+    /// The v2 api replied with one or more errors
+    #[error("the philipshue bridge replied with an error: {}", .0.iter().map(|error| error.description.as_str()).collect::<Vec<_>>().join(", "))]
+    V2ApiError(Vec<crate::v2::ResponseError>),
+    /// The event stream of the bridge sent an event that couldn't be parsed
+    #[error("invalid event: {0}")]
+    InvalidEvent(String),
+    /// The bridge sent a resource that couldn't be parsed
+    #[error("invalid resource: {0}")]
+    InvalidResource(String),
+    /// The frame wasn't sent to the bridge because the bridge wouldn't accept it (for example because it has too many channels)
+    #[cfg(feature = "entertainment")]
+    #[error("invalid frame: {0}")]
+    InvalidFrame(String),
+    /// The entertainment stream couldn't be opened or a frame couldn't be sent
+    #[cfg(feature = "entertainment")]
+    #[error("error while streaming to the philipshue bridge: {0}")]
+    StreamError(String),
+}
+
+impl HueError {
+    /// Returns `true` if the bridge replied with an `ApiError` of the kind `kind`
+    pub fn is_api_error(&self, kind: ApiErrorKind) -> bool {
+        match self {
+            Self::ApiError(error) => error.error_type == kind,
+            Self::PartialStateChange(errors) | Self::StateChangeRejected(errors) => {
+                errors.iter().any(|error| error.error_type == kind)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the login failed because the user didn't press the link button
+    pub fn is_link_button_not_pressed(&self) -> bool {
+        self.is_api_error(ApiErrorKind::LinkButtonNotPressed)
+    }
+
+    /// Returns `true` if the username isn't authorized (anymore)
+    pub fn is_unauthorized(&self) -> bool {
+        self.is_api_error(ApiErrorKind::UnauthorizedUser)
+    }
 }
 
 #[derive(Debug, Deserialize)]
 pub struct ApiError {
     #[serde(rename = "type")]
-    pub error_type: u16,
+    pub error_type: ApiErrorKind,
     /// I don't know what this field is used for
     pub address: String,
     pub description: String,
 }
 
+impl ApiError {
+    /// The name of the attribute the error is about (the last part of the `address`) like `bri`
+    pub fn attribute(&self) -> Option<&str> {
+        crate::light::attribute_of(&self.address)
+    }
+}
+
 impl Display for ApiError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "error_type = {}, ", self.error_type)?;
+        write!(f, "error_type = {}, ", self.error_type.code())?;
         if !self.address.is_empty() {
             write!(f, "address = {}, ", self.address)?;
         }
@@ -37,3 +105,179 @@ impl Display for ApiError {
         Ok(())
     }
 }
+
+/// The type of an `ApiError`, error codes this crate doesn't know are returned as `ApiErrorKind::Other`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(from = "u16")]
+pub enum ApiErrorKind {
+    /// 1: the username is invalid or was deleted
+    UnauthorizedUser,
+    /// 2: the body of the request contains invalid json
+    InvalidJson,
+    /// 3: the resource doesn't exist
+    ResourceNotAvailable,
+    /// 4: the http method isn't supported by the resource
+    MethodNotAvailable,
+    /// 5: the body of the request is missing parameters
+    MissingParameters,
+    /// 6: the parameter doesn't exist for the resource
+    ParameterNotAvailable,
+    /// 7: the value of a parameter is invalid
+    InvalidValue,
+    /// 8: the parameter can't be changed
+    ParameterNotModifiable,
+    /// 11: a list in the body of the request contains too many items
+    TooManyItemsInList,
+    /// 12: the bridge needs a portal connection for this request
+    PortalConnectionRequired,
+    /// 101: the user didn't press the link button of the bridge
+    LinkButtonNotPressed,
+    /// 110: dhcp can't be disabled
+    DhcpCannotBeDisabled,
+    /// 111: the update state is invalid
+    InvalidUpdateState,
+    /// 201: the parameter can't be changed because the device is off
+    DeviceIsOff,
+    /// 301: the group can't be created because the group table is full
+    GroupTableFull,
+    /// 302: the device can't be added to the group because the group table of the device is full
+    DeviceGroupTableFull,
+    /// 304: the device is unreachable
+    DeviceUnreachable,
+    /// 305: groups of this type can't be changed or deleted
+    GroupTypeNotModifiable,
+    /// 306: the light is already used by another entertainment group
+    LightAlreadyUsed,
+    /// 401: the scene couldn't be created
+    SceneCouldNotBeCreated,
+    /// 402: the scene couldn't be created because the scene buffer of the bridge is full
+    SceneBufferFull,
+    /// 403: the scene is locked because it's used by a rule or a schedule
+    SceneLocked,
+    /// 501: the sensor type can't be created
+    SensorTypeNotAllowed,
+    /// 502: the sensor list is full
+    SensorListFull,
+    /// 601: the rule engine is full
+    RuleEngineFull,
+    /// 607: a condition of the rule is invalid
+    ConditionError,
+    /// 608: an action of the rule is invalid
+    ActionError,
+    /// 609: the rule can't be activated
+    UnableToActivate,
+    /// 701: the schedule list is full
+    ScheduleListFull,
+    /// 702: the timezone of the schedule is invalid
+    InvalidScheduleTimezone,
+    /// 703: the time and the local time of a schedule can't be set at the same time
+    ScheduleTimeConflict,
+    /// 704: the schedule can't be created
+    CannotCreateSchedule,
+    /// 705: the schedule can't be enabled because the time is in the past
+    ScheduleTimeInPast,
+    /// 706: the command of the schedule is invalid
+    CommandError,
+    /// 801: the model is invalid
+    InvalidModel,
+    /// 802: the device is factory new
+    FactoryNew,
+    /// 803: the state is invalid
+    InvalidState,
+    /// 901: an internal error of the bridge
+    InternalError,
+    Other(u16),
+}
+
+impl ApiErrorKind {
+    /// The error code the bridge uses for this kind of error
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::UnauthorizedUser => 1,
+            Self::InvalidJson => 2,
+            Self::ResourceNotAvailable => 3,
+            Self::MethodNotAvailable => 4,
+            Self::MissingParameters => 5,
+            Self::ParameterNotAvailable => 6,
+            Self::InvalidValue => 7,
+            Self::ParameterNotModifiable => 8,
+            Self::TooManyItemsInList => 11,
+            Self::PortalConnectionRequired => 12,
+            Self::LinkButtonNotPressed => 101,
+            Self::DhcpCannotBeDisabled => 110,
+            Self::InvalidUpdateState => 111,
+            Self::DeviceIsOff => 201,
+            Self::GroupTableFull => 301,
+            Self::DeviceGroupTableFull => 302,
+            Self::DeviceUnreachable => 304,
+            Self::GroupTypeNotModifiable => 305,
+            Self::LightAlreadyUsed => 306,
+            Self::SceneCouldNotBeCreated => 401,
+            Self::SceneBufferFull => 402,
+            Self::SceneLocked => 403,
+            Self::SensorTypeNotAllowed => 501,
+            Self::SensorListFull => 502,
+            Self::RuleEngineFull => 601,
+            Self::ConditionError => 607,
+            Self::ActionError => 608,
+            Self::UnableToActivate => 609,
+            Self::ScheduleListFull => 701,
+            Self::InvalidScheduleTimezone => 702,
+            Self::ScheduleTimeConflict => 703,
+            Self::CannotCreateSchedule => 704,
+            Self::ScheduleTimeInPast => 705,
+            Self::CommandError => 706,
+            Self::InvalidModel => 801,
+            Self::FactoryNew => 802,
+            Self::InvalidState => 803,
+            Self::InternalError => 901,
+            Self::Other(code) => *code,
+        }
+    }
+}
+
+impl From<u16> for ApiErrorKind {
+    fn from(value: u16) -> Self {
+        match value {
+            1 => Self::UnauthorizedUser,
+            2 => Self::InvalidJson,
+            3 => Self::ResourceNotAvailable,
+            4 => Self::MethodNotAvailable,
+            5 => Self::MissingParameters,
+            6 => Self::ParameterNotAvailable,
+            7 => Self::InvalidValue,
+            8 => Self::ParameterNotModifiable,
+            11 => Self::TooManyItemsInList,
+            12 => Self::PortalConnectionRequired,
+            101 => Self::LinkButtonNotPressed,
+            110 => Self::DhcpCannotBeDisabled,
+            111 => Self::InvalidUpdateState,
+            201 => Self::DeviceIsOff,
+            301 => Self::GroupTableFull,
+            302 => Self::DeviceGroupTableFull,
+            304 => Self::DeviceUnreachable,
+            305 => Self::GroupTypeNotModifiable,
+            306 => Self::LightAlreadyUsed,
+            401 => Self::SceneCouldNotBeCreated,
+            402 => Self::SceneBufferFull,
+            403 => Self::SceneLocked,
+            501 => Self::SensorTypeNotAllowed,
+            502 => Self::SensorListFull,
+            601 => Self::RuleEngineFull,
+            607 => Self::ConditionError,
+            608 => Self::ActionError,
+            609 => Self::UnableToActivate,
+            701 => Self::ScheduleListFull,
+            702 => Self::InvalidScheduleTimezone,
+            703 => Self::ScheduleTimeConflict,
+            704 => Self::CannotCreateSchedule,
+            705 => Self::ScheduleTimeInPast,
+            706 => Self::CommandError,
+            801 => Self::InvalidModel,
+            802 => Self::FactoryNew,
+            803 => Self::InvalidState,
+            901 => Self::InternalError,
+            code => Self::Other(code),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use serde::Serialize;
+
+use crate::{bridge::CreationResponse, error::HueError, Hue};
+
+use super::GroupType;
+
+impl Hue {
+    /// Create a new group and return the id of the created group
+    /// ```
+    /// use philipshue::{Hue, HueBridge, group::{GroupType, NewGroup}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string());
+    ///     let group_id = hue.create_group(
+    ///         NewGroup::new("Kitchen".to_string(), vec!["1".to_string(), "2".to_string()])
+    ///             .group_type(GroupType::Room)
+    ///             .class("Kitchen".to_string())
+    ///     ).await.unwrap();
+    /// }
+    /// ```
+    pub async fn create_group(&self, new_group: NewGroup) -> Result<String, HueError> {
+        Ok(self
+            .bridge
+            .parse_response::<CreationResponse>(
+                self.bridge
+                    .client()
+                    .post(format!("{}/groups", self.get_username_url()))
+                    .json(&new_group)
+                    .send()
+                    .await?,
+            )
+            .await?
+            .id)
+    }
+
+    /// Change the name, the lights or the class of a group
+    pub async fn update_group(
+        &self,
+        group_number: &str,
+        group_change: GroupChange,
+    ) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .put(format!("{}/groups/{group_number}", self.get_username_url()))
+                    .json(&group_change)
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Build a `NewGroup` object to create a group. If no `GroupType` is set the bridge will create a `LightGroup`
+#[derive(Clone, Serialize)]
+pub struct NewGroup {
+    name: String,
+    lights: Vec<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    group_type: Option<GroupType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    class: Option<String>,
+}
+
+impl NewGroup {
+    pub fn new(name: String, lights: Vec<String>) -> Self {
+        Self {
+            name,
+            lights,
+            group_type: None,
+            class: None,
+        }
+    }
+
+    pub fn group_type(mut self, group_type: GroupType) -> Self {
+        self.group_type = Some(group_type);
+        self
+    }
+
+    /// The class is only used for `Room`s and `Zone`s (something like `Living room` or `Kitchen`)
+    pub fn class(mut self, class: String) -> Self {
+        self.class = Some(class);
+        self
+    }
+}
+
+/// Build `GroupChange` objects to change the attributes of a `HueGroup`
+/// ```
+/// use philipshue::group::GroupChange;
+///
+/// let change = GroupChange::new()
+///     .name("Bedroom".to_string())
+///     .lights(vec!["3".to_string(), "4".to_string()]);
+/// ```
+#[derive(Default, Clone, Serialize)]
+pub struct GroupChange {
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    value_name: Option<String>,
+    #[serde(rename = "lights", skip_serializing_if = "Option::is_none")]
+    value_lights: Option<Vec<String>>,
+    #[serde(rename = "class", skip_serializing_if = "Option::is_none")]
+    value_class: Option<String>,
+}
+
+impl GroupChange {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no value was set (every value is `None`)
+    pub fn is_empty(&self) -> bool {
+        self.value_name.is_none() && self.value_lights.is_none() && self.value_class.is_none()
+    }
+
+    pub fn name(mut self, name: String) -> Self {
+        self.value_name = Some(name);
+        self
+    }
+
+    pub fn lights(mut self, lights: Vec<String>) -> Self {
+        self.value_lights = Some(lights);
+        self
+    }
+
+    pub fn class(mut self, class: String) -> Self {
+        self.value_class = Some(class);
+        self
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::HueError,
+    light::{StateChange, StateChangeReport},
+    Hue,
+};
+
+mod group_change;
+mod model;
+
+pub use group_change::*;
+pub use model::*;
+
+impl Hue {
+    /// Get a `HashMap` of all groups the hue bridge know. The key of the `HashMap` identifies the id of the `HueGroup`
+    pub async fn groups(&self) -> Result<HashMap<String, HueGroup>, HueError> {
+        Ok(self
+            .bridge
+            .client()
+            .get(format!("{}/groups", self.get_username_url()))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// Get one specific group from it's `group_number`
+    /// The group `0` is a special group which contains all lights the bridge knows
+    pub async fn get_group(&self, group_number: &str) -> Result<HueGroup, HueError> {
+        Ok(self
+            .bridge
+            .client()
+            .get(format!("{}/groups/{group_number}", self.get_username_url()))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// Delete a group, the lights of the group won't be deleted
+    pub async fn delete_group(&self, group_number: &str) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .delete(format!("{}/groups/{group_number}", self.get_username_url()))
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Change the state of all lights of a group at once
+    /// ```
+    /// use philipshue::{Hue, HueBridge, light::StateChange};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string());
+    ///     hue.set_group_action(
+    ///         "1", // we know the group number from the groups request for example
+    ///         StateChange::new().on(false) // turn all lights of the room off
+    ///     ).await.unwrap();
+    /// }
+    /// ```
+    pub async fn set_group_action(
+        &self,
+        group_number: &str,
+        state_change: StateChange,
+    ) -> Result<StateChangeReport, HueError> {
+        self.bridge
+            .parse_state_change(
+                self.bridge
+                    .client()
+                    .put(format!(
+                        "{}/groups/{group_number}/action",
+                        self.get_username_url()
+                    ))
+                    .json(&state_change)
+                    .send()
+                    .await?,
+            )
+            .await
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct HueGroup {
+    pub name: String,
+    /// The ids of the lights that are part of this group
+    pub lights: Vec<String>,
+    /// The ids of the sensors that are part of this group
+    #[serde(default)]
+    pub sensors: Vec<String>,
+    #[serde(rename = "type")]
+    pub group_type: GroupType,
+    pub state: HueGroupState,
+    pub recycle: Option<bool>,
+    /// The class of a `Room` or a `Zone` like `Living room` or `Kitchen`
+    pub class: Option<String>,
+    /// The last action that was applied to the lights of this group
+    pub action: HueGroupAction,
+    /// Only available for `Entertainment` groups
+    pub stream: Option<HueGroupStream>,
+    /// The positions of the lights of an `Entertainment` group. The key identifies the id of the light
+    pub locations: Option<HashMap<String, Vec<f32>>>,
+}
+
+/// The type of a group, types this crate doesn't know are returned as `GroupType::Other`
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum GroupType {
+    Luminaire,
+    Lightsource,
+    LightGroup,
+    Room,
+    Entertainment,
+    Zone,
+    Other(String),
+}
+
+impl GroupType {
+    /// The name of the type the bridge uses like `LightGroup`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Luminaire => "Luminaire",
+            Self::Lightsource => "Lightsource",
+            Self::LightGroup => "LightGroup",
+            Self::Room => "Room",
+            Self::Entertainment => "Entertainment",
+            Self::Zone => "Zone",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for GroupType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "Luminaire" => Self::Luminaire,
+            "Lightsource" => Self::Lightsource,
+            "LightGroup" => Self::LightGroup,
+            "Room" => Self::Room,
+            "Entertainment" => Self::Entertainment,
+            "Zone" => Self::Zone,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<GroupType> for String {
+    fn from(value: GroupType) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct HueGroupState {
+    /// `true` if all lights of the group are on
+    pub all_on: bool,
+    /// `true` if at least one light of the group is on
+    pub any_on: bool,
+}
+
+#[derive(Deserialize)]
+pub struct HueGroupAction {
+    pub on: Option<bool>,
+    pub bri: Option<u8>,
+    pub hue: Option<u16>,
+    pub sat: Option<u8>,
+    pub effect: Option<String>,
+    pub xy: Option<[f32; 2]>,
+    pub ct: Option<u16>,
+    pub alert: Option<String>,
+    pub colormode: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct HueGroupStream {
+    #[serde(rename = "proxymode")]
+    pub proxy_mode: String,
+    #[serde(rename = "proxynode")]
+    pub proxy_node: String,
+    pub active: bool,
+    pub owner: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GroupType;
+
+    #[test]
+    fn group_type_round_trip() {
+        for name in [
+            "Luminaire",
+            "Lightsource",
+            "LightGroup",
+            "Room",
+            "Entertainment",
+            "Zone",
+        ] {
+            let group_type: GroupType = serde_json::from_value(name.into()).unwrap();
+            assert!(!matches!(group_type, GroupType::Other(_)));
+            assert_eq!(serde_json::to_value(group_type).unwrap(), name);
+        }
+    }
+
+    #[test]
+    fn unknown_group_type_is_kept() {
+        let group_type: GroupType = serde_json::from_value("Matter".into()).unwrap();
+        assert_eq!(group_type, GroupType::Other("Matter".to_string()));
+        assert_eq!(serde_json::to_value(group_type).unwrap(), "Matter");
+    }
+}
@@ -1,13 +1,28 @@
 #![doc = include_str!("../README.md")]
 
 mod bridge;
+mod bridge_builder;
+mod certificate;
+pub mod config;
 mod device;
 mod discover;
+#[cfg(feature = "entertainment")]
+pub mod entertainment;
 mod error;
+pub mod group;
 pub mod light;
 pub mod login;
+pub mod rule;
+pub mod scene;
+pub mod schedule;
+pub mod search;
+pub mod sensor;
+pub mod state;
+pub mod v2;
 
 pub use bridge::HueBridge;
+pub use bridge_builder::HueBridgeBuilder;
+pub use certificate::{CertificateFingerprint, CertificateVerification};
 pub use device::DeviceType;
 pub use discover::*;
 pub use error::*;
@@ -18,11 +33,23 @@ pub struct Hue {
     pub bridge: HueBridge,
     /// The username is something like an authorization token
     pub username: String,
+    /// The client key is needed for entertainment streaming, save it together with the `username`
+    pub client_key: Option<String>,
 }
 
 impl Hue {
     pub fn new(bridge: HueBridge, username: String) -> Self {
-        Self { bridge, username }
+        Self {
+            bridge,
+            username,
+            client_key: None,
+        }
+    }
+
+    /// Set the client key you got while login with `DeviceType::generate_client_key`
+    pub fn with_client_key(mut self, client_key: String) -> Self {
+        self.client_key = Some(client_key);
+        self
     }
 
     /// All authorized request paths are {bridge_url}/api/{username}/{path}
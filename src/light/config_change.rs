@@ -0,0 +1,100 @@
+use serde::Serialize;
+
+use crate::{error::HueError, Hue};
+
+use super::{StartupCustomSettings, StartupMode};
+
+impl Hue {
+    /// Change the config of a light
+    /// ```
+    /// use philipshue::{Hue, HueBridge, light::{LightConfigChange, StartupMode}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string());
+    ///     hue.set_light_config(
+    ///         "1",
+    ///         LightConfigChange::new().startup_mode(StartupMode::Powerfail) // keep the last state after a power failure
+    ///     ).await.unwrap();
+    /// }
+    /// ```
+    pub async fn set_light_config(
+        &self,
+        light_number: &str,
+        config_change: LightConfigChange,
+    ) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .put(format!(
+                        "{}/lights/{light_number}/config",
+                        self.get_username_url()
+                    ))
+                    .json(&config_change)
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Default, Clone, Serialize)]
+struct StartupChange {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<StartupMode>,
+    #[serde(rename = "customsettings", skip_serializing_if = "Option::is_none")]
+    custom_settings: Option<StartupCustomSettings>,
+}
+
+/// Build `LightConfigChange` objects to change the config of a `HueLight`
+/// ```
+/// use philipshue::light::{LightConfigChange, StartupCustomSettings, StartupMode};
+///
+/// let change = LightConfigChange::new()
+///     .startup_mode(StartupMode::Custom)
+///     .startup_custom_settings(StartupCustomSettings {
+///         bri: Some(254),
+///         ct: Some(366),
+///         ..Default::default()
+///     });
+/// ```
+#[derive(Default, Clone, Serialize)]
+pub struct LightConfigChange {
+    #[serde(rename = "archetype", skip_serializing_if = "Option::is_none")]
+    value_archetype: Option<String>,
+    #[serde(rename = "startup", skip_serializing_if = "Option::is_none")]
+    value_startup: Option<StartupChange>,
+}
+
+impl LightConfigChange {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no value was set (every value is `None`)
+    pub fn is_empty(&self) -> bool {
+        self.value_archetype.is_none() && self.value_startup.is_none()
+    }
+
+    /// The archetype describes the look of the light like `sultanbulb` or `ceilinground`
+    pub fn archetype(mut self, archetype: String) -> Self {
+        self.value_archetype = Some(archetype);
+        self
+    }
+
+    /// The state of the light after it's powered on
+    pub fn startup_mode(mut self, mode: StartupMode) -> Self {
+        self.value_startup.get_or_insert_with(Default::default).mode = Some(mode);
+        self
+    }
+
+    /// The state of the light after it's powered on if the startup mode is `StartupMode::Custom`
+    pub fn startup_custom_settings(mut self, custom_settings: StartupCustomSettings) -> Self {
+        self.value_startup
+            .get_or_insert_with(Default::default)
+            .custom_settings = Some(custom_settings);
+        self
+    }
+}
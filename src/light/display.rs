@@ -46,6 +46,10 @@ impl fmt::Display for HueLightConfig {
         writeln!(f, "Archetype: {}", self.archetype)?;
         writeln!(f, "Function: {}", self.function)?;
         writeln!(f, "Direction: {}", self.direction)?;
+        if let Some(startup) = &self.startup {
+            writeln!(f, "Startup mode: {}", startup.mode.as_str())?;
+            writeln!(f, "Startup configured: {}", startup.configured)?;
+        }
         Ok(())
     }
 }
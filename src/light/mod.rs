@@ -1,14 +1,26 @@
 use std::collections::HashMap;
 
+use serde::Serialize;
+
 use crate::{error::HueError, Hue};
 
+mod config_change;
 #[cfg(feature = "display")]
 mod display;
 mod model;
 mod state_change;
+mod state_change_report;
 
+pub use config_change::*;
 pub use model::*;
 pub use state_change::*;
+pub(crate) use state_change_report::attribute_of;
+pub use state_change_report::StateChangeReport;
+
+#[derive(Serialize)]
+struct LightRename {
+    name: String,
+}
 
 impl Hue {
     /// Get a `HashMap` of all lights the hue bridge know. The key of the `HashMap` identifies the id of the `HueLight`
@@ -36,4 +48,33 @@ impl Hue {
             .json()
             .await?)
     }
+
+    /// Change the name of a light
+    pub async fn rename_light(&self, light_number: &str, name: String) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .put(format!("{}/lights/{light_number}", self.get_username_url()))
+                    .json(&LightRename { name })
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Delete a light from the bridge
+    pub async fn delete_light(&self, light_number: &str) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .delete(format!("{}/lights/{light_number}", self.get_username_url()))
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
 }
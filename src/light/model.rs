@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize)]
 pub struct HueLight {
@@ -71,4 +71,100 @@ pub struct HueLightConfig {
     pub archetype: String,
     pub function: String,
     pub direction: String,
+    /// Only available for lights that support changing their startup behaviour
+    pub startup: Option<HueLightStartup>,
+}
+
+#[derive(Deserialize)]
+pub struct HueLightStartup {
+    pub mode: StartupMode,
+    /// `false` if the startup mode is still being applied to the light
+    pub configured: bool,
+    #[serde(rename = "customsettings")]
+    pub custom_settings: Option<StartupCustomSettings>,
+}
+
+/// The state of a light after it's powered on, modes this crate doesn't know are returned as `StartupMode::Other`
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum StartupMode {
+    /// Warm white with full brightness
+    Safety,
+    /// The last state before the power failure
+    Powerfail,
+    /// The last state the light had while it was on
+    LastOnState,
+    /// The state given in the custom settings
+    Custom,
+    /// The bridge doesn't know the startup mode of the light
+    Unknown,
+    Other(String),
+}
+
+impl StartupMode {
+    /// The name of the mode the bridge uses like `lastonstate`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Safety => "safety",
+            Self::Powerfail => "powerfail",
+            Self::LastOnState => "lastonstate",
+            Self::Custom => "custom",
+            Self::Unknown => "unknown",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for StartupMode {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "safety" => Self::Safety,
+            "powerfail" => Self::Powerfail,
+            "lastonstate" => Self::LastOnState,
+            "custom" => Self::Custom,
+            "unknown" => Self::Unknown,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<StartupMode> for String {
+    fn from(value: StartupMode) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+#[derive(Default, Clone, Deserialize, Serialize)]
+pub struct StartupCustomSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bri: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ct: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xy: Option<[f32; 2]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hue: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sat: Option<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StartupMode;
+
+    #[test]
+    fn startup_mode_round_trip() {
+        for name in ["safety", "powerfail", "lastonstate", "custom", "unknown"] {
+            let mode: StartupMode = serde_json::from_value(name.into()).unwrap();
+            assert!(!matches!(mode, StartupMode::Other(_)));
+            assert_eq!(serde_json::to_value(mode).unwrap(), name);
+        }
+    }
+
+    #[test]
+    fn unknown_startup_mode_is_kept() {
+        let mode: StartupMode = serde_json::from_value("dimmed".into()).unwrap();
+        assert_eq!(mode, StartupMode::Other("dimmed".to_string()));
+        assert_eq!(serde_json::to_value(mode).unwrap(), "dimmed");
+    }
 }
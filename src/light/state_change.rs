@@ -2,8 +2,11 @@ use serde::Serialize;
 
 use crate::{error::HueError, Hue};
 
+use super::StateChangeReport;
+
 impl Hue {
     /// Change the state of a light
+    /// The `StateChangeReport` tells you which attributes were applied, a `HueError::ApiError` is only thrown if no attribute was applied
     /// ```
     /// use philipshue::{Hue, HueBridge, light::StateChange};
     ///
@@ -22,9 +25,9 @@ impl Hue {
         &self,
         light_number: &str,
         state_change: StateChange,
-    ) -> Result<(), HueError> {
+    ) -> Result<StateChangeReport, HueError> {
         self.bridge
-            .parse_response::<serde_json::Value>(
+            .parse_state_change(
                 self.bridge
                     .client()
                     .put(format!(
@@ -35,11 +38,32 @@ impl Hue {
                     .send()
                     .await?,
             )
-            .await?;
-        Ok(())
+            .await
     }
 }
 
+/// The alert effect of a light
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Alert {
+    /// Stop the alert effect
+    None,
+    /// Flash once
+    Select,
+    /// Flash for 15 seconds
+    LSelect,
+}
+
+/// The dynamic effect of a light
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Effect {
+    /// Stop the effect
+    None,
+    /// Cycle through all hues with the current brightness and saturation
+    ColorLoop,
+}
+
 /// Build `StateChange` objects to change the state of a `HueLight`
 /// ```
 /// use philipshue::light::StateChange;
@@ -50,6 +74,16 @@ impl Hue {
 ///     .sat(123) // set the saturation to 123
 ///     .hue(123); // set the hue to 123
 /// ```
+/// The bridge doesn't accept a value and it's increment (like `bri` and `bri_inc`) at the same time, so setting one of them removes the other one.
+/// If multiple color modes are set the bridge prefers `xy` over `ct` over `hue` and `sat`.
+/// ```
+/// use philipshue::light::{Alert, StateChange};
+///
+/// let change = StateChange::new()
+///     .bri_inc(-50) // decrease the brightness by 50
+///     .ct(366) // set the color temperature to 366 mirek
+///     .alert(Alert::Select); // flash once
+/// ```
 #[derive(Default, Clone, Serialize)]
 pub struct StateChange {
     #[serde(rename = "on", skip_serializing_if = "Option::is_none")]
@@ -60,8 +94,26 @@ pub struct StateChange {
     value_bri: Option<u8>,
     #[serde(rename = "hue", skip_serializing_if = "Option::is_none")]
     value_hue: Option<u16>,
+    #[serde(rename = "xy", skip_serializing_if = "Option::is_none")]
+    value_xy: Option<[f32; 2]>,
+    #[serde(rename = "ct", skip_serializing_if = "Option::is_none")]
+    value_ct: Option<u16>,
+    #[serde(rename = "alert", skip_serializing_if = "Option::is_none")]
+    value_alert: Option<Alert>,
+    #[serde(rename = "effect", skip_serializing_if = "Option::is_none")]
+    value_effect: Option<Effect>,
     #[serde(rename = "transitiontime", skip_serializing_if = "Option::is_none")]
     value_transition_time: Option<u16>,
+    #[serde(rename = "bri_inc", skip_serializing_if = "Option::is_none")]
+    value_bri_inc: Option<i16>,
+    #[serde(rename = "sat_inc", skip_serializing_if = "Option::is_none")]
+    value_sat_inc: Option<i16>,
+    #[serde(rename = "hue_inc", skip_serializing_if = "Option::is_none")]
+    value_hue_inc: Option<i32>,
+    #[serde(rename = "ct_inc", skip_serializing_if = "Option::is_none")]
+    value_ct_inc: Option<i32>,
+    #[serde(rename = "xy_inc", skip_serializing_if = "Option::is_none")]
+    value_xy_inc: Option<[f32; 2]>,
 }
 
 impl StateChange {
@@ -75,7 +127,16 @@ impl StateChange {
             && self.value_sat.is_none()
             && self.value_bri.is_none()
             && self.value_hue.is_none()
+            && self.value_xy.is_none()
+            && self.value_ct.is_none()
+            && self.value_alert.is_none()
+            && self.value_effect.is_none()
             && self.value_transition_time.is_none()
+            && self.value_bri_inc.is_none()
+            && self.value_sat_inc.is_none()
+            && self.value_hue_inc.is_none()
+            && self.value_ct_inc.is_none()
+            && self.value_xy_inc.is_none()
     }
 
     pub fn on(mut self, on: bool) -> Self {
@@ -83,23 +144,89 @@ impl StateChange {
         self
     }
 
+    /// Removes `sat_inc`
     pub fn sat(mut self, sat: u8) -> Self {
         self.value_sat = Some(sat);
+        self.value_sat_inc = None;
         self
     }
 
+    /// Removes `bri_inc`
     pub fn bri(mut self, bri: u8) -> Self {
         self.value_bri = Some(bri);
+        self.value_bri_inc = None;
         self
     }
 
+    /// Removes `hue_inc`
     pub fn hue(mut self, hue: u16) -> Self {
         self.value_hue = Some(hue);
+        self.value_hue_inc = None;
+        self
+    }
+
+    /// The x and y coordinates of a color in the CIE color space (between `0` and `1`), removes `xy_inc`
+    pub fn xy(mut self, x: f32, y: f32) -> Self {
+        self.value_xy = Some([x, y]);
+        self.value_xy_inc = None;
+        self
+    }
+
+    /// The color temperature in mirek, removes `ct_inc`
+    pub fn ct(mut self, ct: u16) -> Self {
+        self.value_ct = Some(ct);
+        self.value_ct_inc = None;
+        self
+    }
+
+    pub fn alert(mut self, alert: Alert) -> Self {
+        self.value_alert = Some(alert);
         self
     }
 
+    pub fn effect(mut self, effect: Effect) -> Self {
+        self.value_effect = Some(effect);
+        self
+    }
+
+    /// The duration of the transition in multiples of 100ms
     pub fn transition_time(mut self, transition_time: u16) -> Self {
         self.value_transition_time = Some(transition_time);
         self
     }
+
+    /// Increment or decrement the brightness (between `-254` and `254`), removes `bri`
+    pub fn bri_inc(mut self, bri_inc: i16) -> Self {
+        self.value_bri_inc = Some(bri_inc);
+        self.value_bri = None;
+        self
+    }
+
+    /// Increment or decrement the saturation (between `-254` and `254`), removes `sat`
+    pub fn sat_inc(mut self, sat_inc: i16) -> Self {
+        self.value_sat_inc = Some(sat_inc);
+        self.value_sat = None;
+        self
+    }
+
+    /// Increment or decrement the hue (between `-65534` and `65534`), removes `hue`
+    pub fn hue_inc(mut self, hue_inc: i32) -> Self {
+        self.value_hue_inc = Some(hue_inc);
+        self.value_hue = None;
+        self
+    }
+
+    /// Increment or decrement the color temperature (between `-65534` and `65534`), removes `ct`
+    pub fn ct_inc(mut self, ct_inc: i32) -> Self {
+        self.value_ct_inc = Some(ct_inc);
+        self.value_ct = None;
+        self
+    }
+
+    /// Increment or decrement the x and y coordinates (between `-0.5` and `0.5`), removes `xy`
+    pub fn xy_inc(mut self, x_inc: f32, y_inc: f32) -> Self {
+        self.value_xy_inc = Some([x_inc, y_inc]);
+        self.value_xy = None;
+        self
+    }
 }
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use reqwest::Response;
+
+use crate::{
+    bridge::{DataResponse, HueBridge},
+    error::{ApiError, HueError},
+};
+
+/// The bridge replies to a state change with one `success` or `error` object per attribute.
+/// The `StateChangeReport` contains all of them so you can see which attributes were applied.
+/// ```
+/// use philipshue::{Hue, HueBridge, light::StateChange};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string());
+///     let report = hue.set_light_state("1", StateChange::new().on(true).hue(123)).await.unwrap();
+///     if !report.is_applied("hue") {
+///         println!("The hue of the light couldn't be changed");
+///     }
+///     // or turn every rejected attribute into an error
+///     report.strict().unwrap();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct StateChangeReport {
+    /// The attributes that were applied. The key is the name of the attribute like `bri` and the value the new value of the attribute
+    pub applied: HashMap<String, serde_json::Value>,
+    /// The errors for the attributes that were rejected, use `ApiError::attribute` to get the name of the attribute
+    pub errors: Vec<ApiError>,
+}
+
+impl StateChangeReport {
+    /// Returns `true` if the attribute (like `bri`) was applied
+    pub fn is_applied(&self, attribute: &str) -> bool {
+        self.applied.contains_key(attribute)
+    }
+
+    /// Returns `true` if no attribute was rejected
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// The names of the attributes that were rejected
+    pub fn rejected_attributes(&self) -> Vec<&str> {
+        self.errors
+            .iter()
+            .filter_map(|error| error.attribute())
+            .collect()
+    }
+
+    /// Throw an `HueError::PartialStateChange` error containing all `ApiError`s if at least one attribute was rejected.
+    /// A report always has at least one applied attribute, if every attribute was rejected the state change itself already failed
+    pub fn strict(self) -> Result<Self, HueError> {
+        if self.is_complete() {
+            Ok(self)
+        } else {
+            Err(HueError::PartialStateChange(self.errors))
+        }
+    }
+}
+
+impl StateChangeReport {
+    /// Collect the `success` and `error` objects of the bridge.
+    /// If no attribute was applied a single `ApiError` is thrown as `HueError::ApiError` and several as `HueError::StateChangeRejected`
+    fn from_responses(
+        responses: Vec<DataResponse<HashMap<String, serde_json::Value>>>,
+    ) -> Result<Self, HueError> {
+        let mut report = StateChangeReport {
+            applied: HashMap::new(),
+            errors: Vec::new(),
+        };
+        for data in responses {
+            match data {
+                DataResponse::Success(success) => {
+                    report
+                        .applied
+                        .extend(success.into_iter().map(|(address, value)| {
+                            (
+                                attribute_of(&address).unwrap_or(&address).to_string(),
+                                value,
+                            )
+                        }))
+                }
+                DataResponse::Error(error) => report.errors.push(error),
+            }
+        }
+        if report.applied.is_empty() {
+            let mut errors = report.errors;
+            return Err(match errors.len() {
+                0 => HueError::NoData,
+                1 => HueError::ApiError(errors.remove(0)),
+                _ => HueError::StateChangeRejected(errors),
+            });
+        }
+        Ok(report)
+    }
+}
+
+impl HueBridge {
+    /// Parse the response of a state change.
+    /// If no attribute was applied a single `ApiError` is thrown as `HueError::ApiError` and several as `HueError::StateChangeRejected`
+    pub(crate) async fn parse_state_change(
+        &self,
+        response: Response,
+    ) -> Result<StateChangeReport, HueError> {
+        StateChangeReport::from_responses(self.parse_responses(response).await?)
+    }
+}
+
+/// The attribute is the last part of an address like `/lights/1/state/bri`
+pub(crate) fn attribute_of(address: &str) -> Option<&str> {
+    address
+        .rsplit('/')
+        .next()
+        .filter(|attribute| !attribute.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ApiErrorKind;
+
+    fn parse(json: &str) -> Result<StateChangeReport, HueError> {
+        StateChangeReport::from_responses(serde_json::from_str(json).unwrap())
+    }
+
+    #[test]
+    fn all_attributes_applied() {
+        let report = parse(
+            r#"[
+                {"success": {"/lights/1/state/on": true}},
+                {"success": {"/lights/1/state/bri": 200}}
+            ]"#,
+        )
+        .unwrap();
+        assert!(report.is_complete());
+        assert!(report.is_applied("on"));
+        assert_eq!(report.applied["bri"], 200);
+        assert!(report.strict().is_ok());
+    }
+
+    #[test]
+    fn some_attributes_rejected() {
+        let report = parse(
+            r#"[
+                {"success": {"/lights/1/state/on": true}},
+                {"error": {"type": 201, "address": "/lights/1/state/hue", "description": "parameter, hue, is not modifiable. Device is set to off."}},
+                {"error": {"type": 7, "address": "/lights/1/state/bri", "description": "invalid value, 300, for parameter, bri"}}
+            ]"#,
+        )
+        .unwrap();
+        assert!(report.is_applied("on"));
+        assert!(!report.is_complete());
+        assert_eq!(report.rejected_attributes(), vec!["hue", "bri"]);
+        match report.strict() {
+            Err(HueError::PartialStateChange(errors)) => assert_eq!(errors.len(), 2),
+            other => panic!("expected a partial state change, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn all_attributes_rejected() {
+        let error = parse(
+            r#"[
+                {"error": {"type": 201, "address": "/lights/1/state/hue", "description": "parameter, hue, is not modifiable. Device is set to off."}},
+                {"error": {"type": 201, "address": "/lights/1/state/sat", "description": "parameter, sat, is not modifiable. Device is set to off."}}
+            ]"#,
+        )
+        .unwrap_err();
+        match &error {
+            HueError::StateChangeRejected(errors) => {
+                let attributes: Vec<_> = errors.iter().filter_map(ApiError::attribute).collect();
+                assert_eq!(attributes, vec!["hue", "sat"]);
+            }
+            other => panic!("expected a rejected state change, got {other:?}"),
+        }
+        assert!(error.is_api_error(ApiErrorKind::DeviceIsOff));
+    }
+
+    #[test]
+    fn single_attribute_rejected() {
+        let error = parse(
+            r#"[
+                {"error": {"type": 3, "address": "/lights/99/state", "description": "resource, /lights/99/state, not available"}}
+            ]"#,
+        )
+        .unwrap_err();
+        match &error {
+            HueError::ApiError(error) => assert_eq!(error.address, "/lights/99/state"),
+            other => panic!("expected an api error, got {other:?}"),
+        }
+        assert!(error.is_api_error(ApiErrorKind::ResourceNotAvailable));
+    }
+
+    #[test]
+    fn empty_response() {
+        assert!(matches!(parse("[]"), Err(HueError::NoData)));
+    }
+}
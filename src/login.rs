@@ -1,23 +1,154 @@
+use std::time::{Duration, Instant};
+
 use serde::Deserialize;
 
 use crate::{bridge::HueBridge, device::DeviceType, error::HueError, Hue};
 
-/// The server just returns the `username` (something like an authorization token)
+/// The server returns the `username` (something like an authorization token)
 #[derive(Deserialize)]
 pub struct LoginResponse {
     pub username: String,
+    /// The key for entertainment streaming, only available if `DeviceType::generate_client_key` was set
+    #[serde(rename = "clientkey")]
+    pub client_key: Option<String>,
+}
+
+impl LoginResponse {
+    fn into_hue(self, bridge: HueBridge) -> Hue {
+        let hue = Hue::new(bridge, self.username);
+        match self.client_key {
+            Some(client_key) => hue.with_client_key(client_key),
+            None => hue,
+        }
+    }
+}
+
+/// The progress of `HueBridge::pair` after a login attempt failed because the link button wasn't pressed yet
+#[derive(Clone, Debug)]
+pub struct PairProgress {
+    /// The number of login attempts so far
+    pub attempt: u32,
+    /// The time since the pairing was started
+    pub elapsed: Duration,
+    /// The time until the pairing times out
+    pub remaining: Duration,
+}
+
+type ProgressCallback = Box<dyn Fn(&PairProgress) + Send + Sync>;
+
+/// Configure how `HueBridge::pair` waits for the link button
+/// ```
+/// use std::time::Duration;
+/// use philipshue::login::PairOptions;
+///
+/// let options = PairOptions::new()
+///     .interval(Duration::from_secs(2))
+///     .timeout(Duration::from_secs(60))
+///     .on_progress(|progress| {
+///         println!("Press the link button ({} seconds left)", progress.remaining.as_secs())
+///     });
+/// ```
+pub struct PairOptions {
+    interval: Duration,
+    timeout: Duration,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl Default for PairOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(30),
+            on_progress: None,
+        }
+    }
+}
+
+impl PairOptions {
+    /// Poll every second for 30 seconds
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The time between two login attempts
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// The time after which the pairing fails with `HueError::PairingTimeout`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The callback is called after every login attempt that failed because the link button wasn't pressed yet
+    pub fn on_progress<F>(mut self, on_progress: F) -> Self
+    where
+        F: Fn(&PairProgress) + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Box::new(on_progress));
+        self
+    }
 }
 
 impl HueBridge {
+    /// Login with `DeviceType` and retry until the user pressed the link button or the timeout of the `PairOptions` expired.
+    /// A `HueError::PairingTimeout` error is thrown if the link button wasn't pressed in time
+    /// ```
+    /// use philipshue::{DeviceType, HueBridge, login::PairOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let bridge = HueBridge::new("https://philips-hue.local".to_string());
+    ///     println!("Press the link button of your bridge");
+    ///     let hue = bridge
+    ///         .pair(DeviceType::new("my_device_name".to_string()), PairOptions::new())
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn pair(
+        self,
+        device_type: DeviceType,
+        options: PairOptions,
+    ) -> Result<Hue, HueError> {
+        let start = Instant::now();
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            // a bridge that doesn't answer mustn't keep the pairing running past the timeout
+            let remaining = options.timeout.saturating_sub(start.elapsed());
+            let login = self.login_with_response(device_type.clone());
+            match tokio::time::timeout(remaining, login).await {
+                Err(_) => return Err(HueError::PairingTimeout),
+                Ok(Ok(response)) => return Ok(response.into_hue(self)),
+                Ok(Err(e)) if e.is_link_button_not_pressed() => {}
+                Ok(Err(e)) => return Err(e),
+            }
+            let elapsed = start.elapsed();
+            if elapsed + options.interval > options.timeout {
+                return Err(HueError::PairingTimeout);
+            }
+            if let Some(on_progress) = &options.on_progress {
+                on_progress(&PairProgress {
+                    attempt,
+                    elapsed,
+                    remaining: options.timeout - elapsed,
+                });
+            }
+            tokio::time::sleep(options.interval).await;
+        }
+    }
+
     /// Login with `DeviceType` and return a `Hue` object instead of a `LoginResponse`
-    /// `HueError::ApiError` with `ApiError::error_type = 101` (link button not pressed) could be thrown
+    /// `HueError::ApiError` with `ApiErrorKind::LinkButtonNotPressed` could be thrown, check it with `HueError::is_link_button_not_pressed`
     pub async fn login_to_hue(self, device_type: DeviceType) -> Result<Hue, HueError> {
-        let username = self.login_with_response(device_type).await?.username;
-        Ok(Hue::new(self, username))
+        Ok(self.login_with_response(device_type).await?.into_hue(self))
     }
 
     /// Login with `DeviceType` and return a `LoginResponse`
-    /// `HueError::ApiError` with `ApiError::error_type = 101` (link button not pressed) could be thrown
+    /// `HueError::ApiError` with `ApiErrorKind::LinkButtonNotPressed` could be thrown, check it with `HueError::is_link_button_not_pressed`
     pub async fn login_with_response(
         &self,
         device_type: DeviceType,
@@ -32,3 +163,29 @@ impl HueBridge {
         .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn pair_times_out_while_the_bridge_does_not_answer() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let bridge = HueBridge::new(format!("http://{}", listener.local_addr().unwrap()));
+        // accept the connection but never answer the request
+        let server = tokio::spawn(async move {
+            let _connection = listener.accept().await;
+            std::future::pending::<()>().await;
+        });
+        let start = Instant::now();
+        let result = bridge
+            .pair(
+                DeviceType::new("test".to_string()),
+                PairOptions::new().timeout(Duration::from_millis(200)),
+            )
+            .await;
+        assert!(matches!(result, Err(HueError::PairingTimeout)));
+        assert!(start.elapsed() < Duration::from_secs(5));
+        server.abort();
+    }
+}
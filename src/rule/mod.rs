@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use crate::{error::HueError, Hue};
+
+mod model;
+mod rule_change;
+
+pub use model::*;
+pub use rule_change::*;
+
+impl Hue {
+    /// Get a `HashMap` of all rules the hue bridge know. The key of the `HashMap` identifies the id of the `HueRule`
+    pub async fn rules(&self) -> Result<HashMap<String, HueRule>, HueError> {
+        Ok(self
+            .bridge
+            .client()
+            .get(format!("{}/rules", self.get_username_url()))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// Get one specific rule from it's `rule_id`
+    pub async fn get_rule(&self, rule_id: &str) -> Result<HueRule, HueError> {
+        Ok(self
+            .bridge
+            .client()
+            .get(format!("{}/rules/{rule_id}", self.get_username_url()))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// Delete a rule
+    pub async fn delete_rule(&self, rule_id: &str) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .delete(format!("{}/rules/{rule_id}", self.get_username_url()))
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+}
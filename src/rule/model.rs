@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{light::StateChange, schedule::CommandMethod};
+
+#[derive(Deserialize)]
+pub struct HueRule {
+    pub name: String,
+    /// The username of the user who created the rule
+    pub owner: String,
+    pub created: String,
+    /// The time the rule was triggered the last time or `none`
+    #[serde(rename = "lasttriggered")]
+    pub last_triggered: String,
+    #[serde(rename = "timestriggered")]
+    pub times_triggered: u32,
+    pub status: RuleStatus,
+    pub recycle: Option<bool>,
+    pub conditions: Vec<RuleCondition>,
+    pub actions: Vec<RuleAction>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleStatus {
+    Enabled,
+    Disabled,
+    /// A resource the rule uses was deleted
+    ResourceDeleted,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RuleOperator {
+    /// The value equals the value of the condition
+    #[serde(rename = "eq")]
+    Equals,
+    /// The value is greater than the value of the condition
+    #[serde(rename = "gt")]
+    GreaterThan,
+    /// The value is less than the value of the condition
+    #[serde(rename = "lt")]
+    LessThan,
+    /// The value has changed
+    #[serde(rename = "dx")]
+    Changed,
+    /// The value has changed after the time given in the value of the condition
+    #[serde(rename = "ddx")]
+    ChangedDelayed,
+    /// The value hasn't changed for the time given in the value of the condition
+    #[serde(rename = "stable")]
+    Stable,
+    /// The value has changed within the time given in the value of the condition
+    #[serde(rename = "not stable")]
+    NotStable,
+    /// The current time is in the time interval given in the value of the condition
+    #[serde(rename = "in")]
+    In,
+    /// The current time isn't in the time interval given in the value of the condition
+    #[serde(rename = "not in")]
+    NotIn,
+}
+
+/// A condition of a rule, the rule is triggered if all conditions are met
+/// ```
+/// use philipshue::rule::{RuleCondition, RuleOperator};
+///
+/// // the button 1 of the switch with the sensor number 2 was released after a short press
+/// let condition = RuleCondition::new("/sensors/2/state/buttonevent".to_string(), RuleOperator::Equals)
+///     .value("1002".to_string());
+/// ```
+#[derive(Clone, Deserialize, Serialize)]
+pub struct RuleCondition {
+    /// The path of the attribute like `/sensors/2/state/buttonevent`
+    pub address: String,
+    pub operator: RuleOperator,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+impl RuleCondition {
+    pub fn new(address: String, operator: RuleOperator) -> Self {
+        Self {
+            address,
+            operator,
+            value: None,
+        }
+    }
+
+    /// The bridge expects every value as string, even numbers and booleans
+    pub fn value(mut self, value: String) -> Self {
+        self.value = Some(value);
+        self
+    }
+}
+
+/// An action of a rule that is executed if the rule is triggered
+/// ```
+/// use philipshue::{light::StateChange, rule::RuleAction};
+///
+/// // turn all lights off
+/// let action = RuleAction::group_action("0", &StateChange::new().on(false));
+/// ```
+#[derive(Clone, Deserialize, Serialize)]
+pub struct RuleAction {
+    /// The path of the request without the username like `/groups/0/action`
+    pub address: String,
+    pub method: CommandMethod,
+    pub body: serde_json::Value,
+}
+
+impl RuleAction {
+    pub fn new(address: String, method: CommandMethod, body: serde_json::Value) -> Self {
+        Self {
+            address,
+            method,
+            body,
+        }
+    }
+
+    /// Change the state of the light `light_number`
+    pub fn light_state(light_number: &str, state_change: &StateChange) -> Self {
+        Self::new(
+            format!("/lights/{light_number}/state"),
+            CommandMethod::Put,
+            serde_json::to_value(state_change).unwrap(),
+        )
+    }
+
+    /// Change the state of all lights of the group `group_number`
+    pub fn group_action(group_number: &str, state_change: &StateChange) -> Self {
+        Self::new(
+            format!("/groups/{group_number}/action"),
+            CommandMethod::Put,
+            serde_json::to_value(state_change).unwrap(),
+        )
+    }
+
+    /// Recall the scene `scene_id` for the lights of the group `group_number`
+    pub fn recall_scene(group_number: &str, scene_id: &str) -> Self {
+        Self::new(
+            format!("/groups/{group_number}/action"),
+            CommandMethod::Put,
+            serde_json::json!({ "scene": scene_id }),
+        )
+    }
+}
@@ -0,0 +1,194 @@
+use serde::Serialize;
+
+use crate::{bridge::CreationResponse, error::HueError, Hue};
+
+use super::{RuleAction, RuleCondition, RuleStatus};
+
+/// The bridge doesn't accept rules with more conditions
+pub const MAX_RULE_CONDITIONS: usize = 8;
+/// The bridge doesn't accept rules with more actions
+pub const MAX_RULE_ACTIONS: usize = 8;
+
+/// Check the number of conditions and actions before the request is sent
+fn validate(
+    conditions: Option<&Vec<RuleCondition>>,
+    actions: Option<&Vec<RuleAction>>,
+) -> Result<(), HueError> {
+    if let Some(conditions) = conditions {
+        if conditions.is_empty() || conditions.len() > MAX_RULE_CONDITIONS {
+            return Err(HueError::InvalidRule(format!(
+                "a rule needs 1 to {MAX_RULE_CONDITIONS} conditions but got {}",
+                conditions.len()
+            )));
+        }
+    }
+    if let Some(actions) = actions {
+        if actions.is_empty() || actions.len() > MAX_RULE_ACTIONS {
+            return Err(HueError::InvalidRule(format!(
+                "a rule needs 1 to {MAX_RULE_ACTIONS} actions but got {}",
+                actions.len()
+            )));
+        }
+    }
+    Ok(())
+}
+
+impl Hue {
+    /// Create a new rule and return the id of the created rule
+    /// A `HueError::InvalidRule` error is returned without sending the request if the rule has no or too many conditions or actions
+    /// ```
+    /// use philipshue::{Hue, HueBridge, light::StateChange, rule::{NewRule, RuleAction, RuleCondition, RuleOperator}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string());
+    ///     let rule_id = hue.create_rule(
+    ///         NewRule::new("Switch on".to_string())
+    ///             .condition(
+    ///                 RuleCondition::new("/sensors/2/state/buttonevent".to_string(), RuleOperator::Equals)
+    ///                     .value("1002".to_string())
+    ///             )
+    ///             .condition(RuleCondition::new("/sensors/2/state/lastupdated".to_string(), RuleOperator::Changed))
+    ///             .action(RuleAction::group_action("1", &StateChange::new().on(true)))
+    ///     ).await.unwrap();
+    /// }
+    /// ```
+    pub async fn create_rule(&self, new_rule: NewRule) -> Result<String, HueError> {
+        validate(Some(&new_rule.conditions), Some(&new_rule.actions))?;
+        Ok(self
+            .bridge
+            .parse_response::<CreationResponse>(
+                self.bridge
+                    .client()
+                    .post(format!("{}/rules", self.get_username_url()))
+                    .json(&new_rule)
+                    .send()
+                    .await?,
+            )
+            .await?
+            .id)
+    }
+
+    /// Change the attributes of a rule
+    /// A `HueError::InvalidRule` error is returned without sending the request if the rule would have no or too many conditions or actions
+    pub async fn update_rule(
+        &self,
+        rule_id: &str,
+        rule_change: RuleChange,
+    ) -> Result<(), HueError> {
+        validate(
+            rule_change.value_conditions.as_ref(),
+            rule_change.value_actions.as_ref(),
+        )?;
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .put(format!("{}/rules/{rule_id}", self.get_username_url()))
+                    .json(&rule_change)
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Build a `NewRule` object to create a rule
+#[derive(Clone, Serialize)]
+pub struct NewRule {
+    name: String,
+    conditions: Vec<RuleCondition>,
+    actions: Vec<RuleAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<RuleStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recycle: Option<bool>,
+}
+
+impl NewRule {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            conditions: Vec::new(),
+            actions: Vec::new(),
+            status: None,
+            recycle: None,
+        }
+    }
+
+    /// Add a condition, the rule is triggered if all conditions are met
+    pub fn condition(mut self, condition: RuleCondition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Add an action that is executed if the rule is triggered
+    pub fn action(mut self, action: RuleAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    pub fn status(mut self, status: RuleStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn recycle(mut self, recycle: bool) -> Self {
+        self.recycle = Some(recycle);
+        self
+    }
+}
+
+/// Build `RuleChange` objects to change the attributes of a `HueRule`.
+/// The conditions and actions replace all conditions and actions of the rule
+/// ```
+/// use philipshue::rule::{RuleChange, RuleStatus};
+///
+/// let change = RuleChange::new().status(RuleStatus::Disabled);
+/// ```
+#[derive(Default, Clone, Serialize)]
+pub struct RuleChange {
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    value_name: Option<String>,
+    #[serde(rename = "conditions", skip_serializing_if = "Option::is_none")]
+    value_conditions: Option<Vec<RuleCondition>>,
+    #[serde(rename = "actions", skip_serializing_if = "Option::is_none")]
+    value_actions: Option<Vec<RuleAction>>,
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    value_status: Option<RuleStatus>,
+}
+
+impl RuleChange {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no value was set (every value is `None`)
+    pub fn is_empty(&self) -> bool {
+        self.value_name.is_none()
+            && self.value_conditions.is_none()
+            && self.value_actions.is_none()
+            && self.value_status.is_none()
+    }
+
+    pub fn name(mut self, name: String) -> Self {
+        self.value_name = Some(name);
+        self
+    }
+
+    pub fn conditions(mut self, conditions: Vec<RuleCondition>) -> Self {
+        self.value_conditions = Some(conditions);
+        self
+    }
+
+    pub fn actions(mut self, actions: Vec<RuleAction>) -> Self {
+        self.value_actions = Some(actions);
+        self
+    }
+
+    pub fn status(mut self, status: RuleStatus) -> Self {
+        self.value_status = Some(status);
+        self
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::{error::HueError, Hue};
+
+mod model;
+mod scene_change;
+
+pub use model::*;
+pub use scene_change::*;
+
+/// Scenes are recalled by setting the `scene` attribute of a group action
+#[derive(Serialize)]
+struct SceneRecall<'a> {
+    scene: &'a str,
+}
+
+impl Hue {
+    /// Get a `HashMap` of all scenes the hue bridge know. The key of the `HashMap` identifies the id of the `HueScene`
+    /// The `light_states` of the scenes are not included, use `Hue::get_scene` to get them
+    pub async fn scenes(&self) -> Result<HashMap<String, HueScene>, HueError> {
+        Ok(self
+            .bridge
+            .client()
+            .get(format!("{}/scenes", self.get_username_url()))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// Get one specific scene including it's `light_states` from it's `scene_id`
+    pub async fn get_scene(&self, scene_id: &str) -> Result<HueScene, HueError> {
+        Ok(self
+            .bridge
+            .client()
+            .get(format!("{}/scenes/{scene_id}", self.get_username_url()))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// Delete a scene
+    pub async fn delete_scene(&self, scene_id: &str) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .delete(format!("{}/scenes/{scene_id}", self.get_username_url()))
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Recall a scene. Only the lights of the scene that are also part of the group `group_number` will change their state.
+    /// Use the group `0` to recall the scene for all lights
+    /// ```
+    /// use philipshue::{Hue, HueBridge};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string());
+    ///     hue.recall_scene("0", "scene_id").await.unwrap();
+    /// }
+    /// ```
+    pub async fn recall_scene(&self, group_number: &str, scene_id: &str) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .put(format!(
+                        "{}/groups/{group_number}/action",
+                        self.get_username_url()
+                    ))
+                    .json(&SceneRecall { scene: scene_id })
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+
+#[derive(Deserialize)]
+pub struct HueScene {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub scene_type: SceneType,
+    /// The group the scene belongs to (only available for `GroupScene`s)
+    pub group: Option<String>,
+    /// The ids of the lights that are part of this scene
+    pub lights: Vec<String>,
+    /// The username of the user who created the scene
+    pub owner: String,
+    pub recycle: bool,
+    /// A locked scene is used by a rule or a schedule and can't be deleted
+    pub locked: bool,
+    #[serde(rename = "appdata", default, deserialize_with = "deserialize_app_data")]
+    pub app_data: Option<SceneAppData>,
+    pub picture: Option<String>,
+    #[serde(rename = "lastupdated")]
+    pub last_updated: Option<String>,
+    pub version: Option<u8>,
+    /// The states of the lights in this scene. The key identifies the id of the light.
+    /// The bridge only replies with the light states if you request one specific scene
+    #[serde(rename = "lightstates")]
+    pub light_states: Option<HashMap<String, SceneLightState>>,
+}
+
+/// The type of a scene, types this crate doesn't know are returned as `SceneType::Other`
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum SceneType {
+    LightScene,
+    GroupScene,
+    Other(String),
+}
+
+impl SceneType {
+    /// The name of the type the bridge uses like `GroupScene`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::LightScene => "LightScene",
+            Self::GroupScene => "GroupScene",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for SceneType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "LightScene" => Self::LightScene,
+            "GroupScene" => Self::GroupScene,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<SceneType> for String {
+    fn from(value: SceneType) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+/// Data applications can store in a scene
+#[derive(Clone, Deserialize, Serialize)]
+pub struct SceneAppData {
+    pub version: u16,
+    pub data: String,
+}
+
+/// Scenes without app data have an empty `appdata` object
+fn deserialize_app_data<'de, D>(deserializer: D) -> Result<Option<SceneAppData>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        Some(value) if value.as_object().is_some_and(|object| !object.is_empty()) => {
+            SceneAppData::deserialize(value)
+                .map(Some)
+                .map_err(D::Error::custom)
+        }
+        _ => Ok(None),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SceneLightState {
+    pub on: Option<bool>,
+    pub bri: Option<u8>,
+    pub hue: Option<u16>,
+    pub sat: Option<u8>,
+    pub xy: Option<[f32; 2]>,
+    pub ct: Option<u16>,
+    pub effect: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn scenes_of_the_bridge() {
+        let scenes: HashMap<String, HueScene> = serde_json::from_value(json!({
+            "4e1c6b20e-on-0": {
+                "name": "Kathy on 1449133269486",
+                "type": "LightScene",
+                "lights": ["2", "3"],
+                "owner": "ffffffffe0341b1b376a2389376a2389",
+                "recycle": true,
+                "locked": false,
+                "appdata": {},
+                "picture": "",
+                "lastupdated": "2015-12-03T08:57:13",
+                "version": 1
+            },
+            "ab341ef24": {
+                "name": "Relax",
+                "type": "GroupScene",
+                "group": "1",
+                "lights": ["1", "2", "3"],
+                "owner": "ffffffffe0341b1b376a2389376a2389",
+                "recycle": false,
+                "locked": true,
+                "appdata": {"version": 1, "data": "myAppData"},
+                "picture": "",
+                "lastupdated": "2015-12-03T10:09:22",
+                "version": 2
+            }
+        }))
+        .unwrap();
+        let light_scene = &scenes["4e1c6b20e-on-0"];
+        assert_eq!(light_scene.scene_type, SceneType::LightScene);
+        assert_eq!(light_scene.group, None);
+        assert!(light_scene.app_data.is_none());
+        assert!(light_scene.light_states.is_none());
+        let group_scene = &scenes["ab341ef24"];
+        assert_eq!(group_scene.scene_type, SceneType::GroupScene);
+        assert_eq!(group_scene.group.as_deref(), Some("1"));
+        assert_eq!(group_scene.app_data.as_ref().unwrap().data, "myAppData");
+    }
+
+    #[test]
+    fn scene_with_light_states() {
+        let scene: HueScene = serde_json::from_value(json!({
+            "name": "Relax",
+            "type": "GroupScene",
+            "group": "1",
+            "lights": ["1", "2"],
+            "owner": "ffffffffe0341b1b376a2389376a2389",
+            "recycle": false,
+            "locked": false,
+            "lastupdated": "2015-12-03T10:09:22",
+            "version": 2,
+            "lightstates": {
+                "1": {"on": true, "bri": 144, "xy": [0.5019, 0.4152]},
+                "2": {"on": false}
+            }
+        }))
+        .unwrap();
+        let light_states = scene.light_states.unwrap();
+        assert_eq!(light_states["1"].bri, Some(144));
+        assert_eq!(light_states["2"].on, Some(false));
+    }
+
+    #[test]
+    fn unknown_scene_type_is_kept() {
+        let scene_type: SceneType = serde_json::from_value("SmartScene".into()).unwrap();
+        assert_eq!(scene_type, SceneType::Other("SmartScene".to_string()));
+        assert_eq!(serde_json::to_value(scene_type).unwrap(), "SmartScene");
+        for name in ["LightScene", "GroupScene"] {
+            let scene_type = SceneType::from(name.to_string());
+            assert!(!matches!(scene_type, SceneType::Other(_)));
+            assert_eq!(String::from(scene_type), name);
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::{
+    bridge::CreationResponse,
+    error::HueError,
+    light::{StateChange, StateChangeReport},
+    Hue,
+};
+
+use super::{SceneAppData, SceneType};
+
+impl Hue {
+    /// Create a new scene and return the id of the created scene
+    /// If you don't set any light states the bridge saves the current states of the lights
+    /// ```
+    /// use philipshue::{Hue, HueBridge, light::StateChange, scene::NewScene};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string());
+    ///     let scene_id = hue.create_scene(
+    ///         NewScene::group_scene("Relax".to_string(), "1".to_string())
+    ///             .light_state("1".to_string(), StateChange::new().on(true).bri(100))
+    ///     ).await.unwrap();
+    /// }
+    /// ```
+    pub async fn create_scene(&self, new_scene: NewScene) -> Result<String, HueError> {
+        Ok(self
+            .bridge
+            .parse_response::<CreationResponse>(
+                self.bridge
+                    .client()
+                    .post(format!("{}/scenes", self.get_username_url()))
+                    .json(&new_scene)
+                    .send()
+                    .await?,
+            )
+            .await?
+            .id)
+    }
+
+    /// Change the attributes of a scene
+    pub async fn update_scene(
+        &self,
+        scene_id: &str,
+        scene_change: SceneChange,
+    ) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .put(format!("{}/scenes/{scene_id}", self.get_username_url()))
+                    .json(&scene_change)
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Change the state a light will have if the scene gets recalled
+    pub async fn set_scene_light_state(
+        &self,
+        scene_id: &str,
+        light_number: &str,
+        state_change: StateChange,
+    ) -> Result<StateChangeReport, HueError> {
+        self.bridge
+            .parse_state_change(
+                self.bridge
+                    .client()
+                    .put(format!(
+                        "{}/scenes/{scene_id}/lightstates/{light_number}",
+                        self.get_username_url()
+                    ))
+                    .json(&state_change)
+                    .send()
+                    .await?,
+            )
+            .await
+    }
+}
+
+/// Build a `NewScene` object to create a scene.
+/// A `LightScene` contains a list of lights, a `GroupScene` belongs to a group and contains the lights of the group.
+#[derive(Clone, Serialize)]
+pub struct NewScene {
+    name: String,
+    #[serde(rename = "type")]
+    scene_type: SceneType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lights: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recycle: Option<bool>,
+    #[serde(rename = "appdata", skip_serializing_if = "Option::is_none")]
+    app_data: Option<SceneAppData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    picture: Option<String>,
+    #[serde(rename = "lightstates", skip_serializing_if = "HashMap::is_empty")]
+    light_states: HashMap<String, StateChange>,
+}
+
+impl NewScene {
+    fn new(name: String, scene_type: SceneType) -> Self {
+        Self {
+            name,
+            scene_type,
+            lights: None,
+            group: None,
+            recycle: None,
+            app_data: None,
+            picture: None,
+            light_states: HashMap::new(),
+        }
+    }
+
+    /// Create a `LightScene` containing the `lights`
+    pub fn light_scene(name: String, lights: Vec<String>) -> Self {
+        Self {
+            lights: Some(lights),
+            ..Self::new(name, SceneType::LightScene)
+        }
+    }
+
+    /// Create a `GroupScene` containing all lights of the group
+    pub fn group_scene(name: String, group: String) -> Self {
+        Self {
+            group: Some(group),
+            ..Self::new(name, SceneType::GroupScene)
+        }
+    }
+
+    /// If `recycle` is `true` the bridge may delete the scene if there is no space for new scenes
+    pub fn recycle(mut self, recycle: bool) -> Self {
+        self.recycle = Some(recycle);
+        self
+    }
+
+    pub fn app_data(mut self, app_data: SceneAppData) -> Self {
+        self.app_data = Some(app_data);
+        self
+    }
+
+    pub fn picture(mut self, picture: String) -> Self {
+        self.picture = Some(picture);
+        self
+    }
+
+    /// Set the state of one light explicitly instead of using the current state of the light
+    pub fn light_state(mut self, light_number: String, state_change: StateChange) -> Self {
+        self.light_states.insert(light_number, state_change);
+        self
+    }
+}
+
+/// Build `SceneChange` objects to change the attributes of a `HueScene`
+/// ```
+/// use philipshue::scene::SceneChange;
+///
+/// let change = SceneChange::new()
+///     .name("Relax".to_string())
+///     .store_light_state(true); // overwrite the light states with the current states of the lights
+/// ```
+#[derive(Default, Clone, Serialize)]
+pub struct SceneChange {
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    value_name: Option<String>,
+    #[serde(rename = "lights", skip_serializing_if = "Option::is_none")]
+    value_lights: Option<Vec<String>>,
+    #[serde(rename = "storelightstate", skip_serializing_if = "Option::is_none")]
+    value_store_light_state: Option<bool>,
+    #[serde(rename = "appdata", skip_serializing_if = "Option::is_none")]
+    value_app_data: Option<SceneAppData>,
+    #[serde(rename = "picture", skip_serializing_if = "Option::is_none")]
+    value_picture: Option<String>,
+}
+
+impl SceneChange {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no value was set (every value is `None`)
+    pub fn is_empty(&self) -> bool {
+        self.value_name.is_none()
+            && self.value_lights.is_none()
+            && self.value_store_light_state.is_none()
+            && self.value_app_data.is_none()
+            && self.value_picture.is_none()
+    }
+
+    pub fn name(mut self, name: String) -> Self {
+        self.value_name = Some(name);
+        self
+    }
+
+    /// Change the lights of a `LightScene`
+    pub fn lights(mut self, lights: Vec<String>) -> Self {
+        self.value_lights = Some(lights);
+        self
+    }
+
+    /// If `true` the light states of the scene are overwritten with the current states of the lights
+    pub fn store_light_state(mut self, store_light_state: bool) -> Self {
+        self.value_store_light_state = Some(store_light_state);
+        self
+    }
+
+    pub fn app_data(mut self, app_data: SceneAppData) -> Self {
+        self.value_app_data = Some(app_data);
+        self
+    }
+
+    pub fn picture(mut self, picture: String) -> Self {
+        self.value_picture = Some(picture);
+        self
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use crate::{error::HueError, Hue};
+
+mod model;
+mod schedule_change;
+mod time_pattern;
+
+pub use model::*;
+pub use schedule_change::*;
+pub use time_pattern::*;
+
+impl Hue {
+    /// Get a `HashMap` of all schedules the hue bridge know. The key of the `HashMap` identifies the id of the `HueSchedule`
+    pub async fn schedules(&self) -> Result<HashMap<String, HueSchedule>, HueError> {
+        Ok(self
+            .bridge
+            .client()
+            .get(format!("{}/schedules", self.get_username_url()))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// Get one specific schedule from it's `schedule_id`
+    pub async fn get_schedule(&self, schedule_id: &str) -> Result<HueSchedule, HueError> {
+        Ok(self
+            .bridge
+            .client()
+            .get(format!(
+                "{}/schedules/{schedule_id}",
+                self.get_username_url()
+            ))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// Delete a schedule
+    pub async fn delete_schedule(&self, schedule_id: &str) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .delete(format!(
+                        "{}/schedules/{schedule_id}",
+                        self.get_username_url()
+                    ))
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+}
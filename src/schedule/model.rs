@@ -0,0 +1,191 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::light::StateChange;
+
+use super::HueTimePattern;
+
+#[derive(Deserialize)]
+pub struct HueSchedule {
+    pub name: String,
+    pub description: String,
+    pub command: ScheduleCommand,
+    /// The time the schedule is triggered in the local time of the bridge, patterns this crate doesn't know are returned as `HueTimePattern::Other`
+    #[serde(rename = "localtime")]
+    pub local_time: HueTimePattern,
+    pub created: String,
+    pub status: ScheduleStatus,
+    /// If `true` the schedule will be deleted after it was triggered (only for non recurring schedules)
+    #[serde(rename = "autodelete")]
+    pub auto_delete: Option<bool>,
+    /// The time a timer was started
+    #[serde(rename = "starttime")]
+    pub start_time: Option<String>,
+    pub recycle: Option<bool>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScheduleStatus {
+    Enabled,
+    Disabled,
+}
+
+/// The http method of a command that is executed by the bridge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CommandMethod {
+    Post,
+    Put,
+    Delete,
+}
+
+/// The request the bridge executes if a schedule is triggered
+/// ```
+/// use philipshue::{light::StateChange, schedule::ScheduleCommand};
+///
+/// // turn all lights of the group 1 on
+/// let command = ScheduleCommand::group_action("username", "1", &StateChange::new().on(true));
+/// ```
+#[derive(Clone, Deserialize, Serialize)]
+pub struct ScheduleCommand {
+    /// The path of the request including the username like `/api/{username}/lights/1/state`
+    pub address: String,
+    pub method: CommandMethod,
+    pub body: CommandBody,
+}
+
+/// The body of a `ScheduleCommand`.
+/// The bridge doesn't tell which kind of body a command has, so the commands of `Hue::schedules` always have a `CommandBody::Raw` body
+#[derive(Clone, Serialize)]
+#[serde(untagged)]
+pub enum CommandBody {
+    /// The body of `/lights/{light_number}/state`
+    LightState(StateChange),
+    /// The body of `/groups/{group_number}/action`
+    GroupAction(StateChange),
+    /// Any other body
+    Raw(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for CommandBody {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serde_json::Value::deserialize(deserializer).map(Self::Raw)
+    }
+}
+
+impl ScheduleCommand {
+    pub fn new(address: String, method: CommandMethod, body: CommandBody) -> Self {
+        Self {
+            address,
+            method,
+            body,
+        }
+    }
+
+    /// Change the state of the light `light_number`
+    pub fn light_state(username: &str, light_number: &str, state_change: &StateChange) -> Self {
+        Self::new(
+            format!("/api/{username}/lights/{light_number}/state"),
+            CommandMethod::Put,
+            CommandBody::LightState(state_change.clone()),
+        )
+    }
+
+    /// Change the state of all lights of the group `group_number`
+    pub fn group_action(username: &str, group_number: &str, state_change: &StateChange) -> Self {
+        Self::new(
+            format!("/api/{username}/groups/{group_number}/action"),
+            CommandMethod::Put,
+            CommandBody::GroupAction(state_change.clone()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::schedule::HueTimePattern;
+
+    #[test]
+    fn command_bodies_are_sent_as_json() {
+        let state_change = StateChange::new().on(true);
+        assert_eq!(
+            serde_json::to_value(ScheduleCommand::light_state("username", "1", &state_change))
+                .unwrap(),
+            json!({
+                "address": "/api/username/lights/1/state",
+                "method": "PUT",
+                "body": {"on": true}
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(ScheduleCommand::group_action(
+                "username",
+                "2",
+                &state_change
+            ))
+            .unwrap(),
+            json!({
+                "address": "/api/username/groups/2/action",
+                "method": "PUT",
+                "body": {"on": true}
+            })
+        );
+        let command = ScheduleCommand::new(
+            "/api/username/groups/0/action".to_string(),
+            CommandMethod::Put,
+            CommandBody::Raw(json!({"scene": "ab341ef24"})),
+        );
+        assert_eq!(
+            serde_json::to_value(command).unwrap()["body"],
+            json!({"scene": "ab341ef24"})
+        );
+    }
+
+    #[test]
+    fn schedule_of_the_bridge() {
+        let schedule: HueSchedule = serde_json::from_value(json!({
+            "name": "Wake up",
+            "description": "Every working day",
+            "command": {
+                "address": "/api/username/groups/1/action",
+                "method": "PUT",
+                "body": {"on": true, "bri": 254}
+            },
+            "localtime": "W124/T07:00:00",
+            "created": "2023-11-14T22:13:20",
+            "status": "enabled",
+            "recycle": false
+        }))
+        .unwrap();
+        assert!(matches!(schedule.command.body, CommandBody::Raw(ref body) if body["bri"] == 254));
+        assert!(matches!(
+            schedule.local_time,
+            HueTimePattern::Recurring { .. }
+        ));
+        assert_eq!(schedule.status, ScheduleStatus::Enabled);
+    }
+
+    #[test]
+    fn unknown_time_pattern_is_kept() {
+        let schedule: HueSchedule = serde_json::from_value(json!({
+            "name": "Sunrise",
+            "description": "",
+            "command": {"address": "/api/username/sensors/1/state", "method": "PUT", "body": {"flag": true}},
+            "localtime": "sunrise+00:30:00",
+            "created": "2023-11-14T22:13:20",
+            "status": "disabled"
+        }))
+        .unwrap();
+        assert_eq!(
+            schedule.local_time,
+            HueTimePattern::Other("sunrise+00:30:00".to_string())
+        );
+        assert_eq!(schedule.local_time.to_string(), "sunrise+00:30:00");
+    }
+}
@@ -0,0 +1,186 @@
+use serde::Serialize;
+
+use crate::{bridge::CreationResponse, error::HueError, Hue};
+
+use super::{HueTimePattern, ScheduleCommand, ScheduleStatus};
+
+impl Hue {
+    /// Create a new schedule and return the id of the created schedule
+    /// ```
+    /// use philipshue::{Hue, HueBridge, light::StateChange, schedule::{NewSchedule, ScheduleCommand}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string());
+    ///     let schedule_id = hue.create_schedule(
+    ///         NewSchedule::new(
+    ///             ScheduleCommand::group_action(&hue.username, "1", &StateChange::new().on(true)),
+    ///             "W124/T07:00:00".parse().unwrap(), // every working day at 7 am
+    ///         )
+    ///         .name("Wake up".to_string())
+    ///     ).await.unwrap();
+    /// }
+    /// ```
+    pub async fn create_schedule(&self, new_schedule: NewSchedule) -> Result<String, HueError> {
+        Ok(self
+            .bridge
+            .parse_response::<CreationResponse>(
+                self.bridge
+                    .client()
+                    .post(format!("{}/schedules", self.get_username_url()))
+                    .json(&new_schedule)
+                    .send()
+                    .await?,
+            )
+            .await?
+            .id)
+    }
+
+    /// Change the attributes of a schedule
+    pub async fn update_schedule(
+        &self,
+        schedule_id: &str,
+        schedule_change: ScheduleChange,
+    ) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .put(format!(
+                        "{}/schedules/{schedule_id}",
+                        self.get_username_url()
+                    ))
+                    .json(&schedule_change)
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Build a `NewSchedule` object to create a schedule
+#[derive(Clone, Serialize)]
+pub struct NewSchedule {
+    command: ScheduleCommand,
+    #[serde(rename = "localtime")]
+    local_time: HueTimePattern,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<ScheduleStatus>,
+    #[serde(rename = "autodelete", skip_serializing_if = "Option::is_none")]
+    auto_delete: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recycle: Option<bool>,
+}
+
+impl NewSchedule {
+    pub fn new(command: ScheduleCommand, local_time: HueTimePattern) -> Self {
+        Self {
+            command,
+            local_time,
+            name: None,
+            description: None,
+            status: None,
+            auto_delete: None,
+            recycle: None,
+        }
+    }
+
+    pub fn name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn status(mut self, status: ScheduleStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// If `true` the schedule will be deleted after it was triggered (only for non recurring schedules)
+    pub fn auto_delete(mut self, auto_delete: bool) -> Self {
+        self.auto_delete = Some(auto_delete);
+        self
+    }
+
+    pub fn recycle(mut self, recycle: bool) -> Self {
+        self.recycle = Some(recycle);
+        self
+    }
+}
+
+/// Build `ScheduleChange` objects to change the attributes of a `HueSchedule`
+/// ```
+/// use philipshue::schedule::{ScheduleChange, ScheduleStatus};
+///
+/// let change = ScheduleChange::new().status(ScheduleStatus::Disabled);
+/// ```
+#[derive(Default, Clone, Serialize)]
+pub struct ScheduleChange {
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    value_name: Option<String>,
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    value_description: Option<String>,
+    #[serde(rename = "command", skip_serializing_if = "Option::is_none")]
+    value_command: Option<ScheduleCommand>,
+    #[serde(rename = "localtime", skip_serializing_if = "Option::is_none")]
+    value_local_time: Option<HueTimePattern>,
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    value_status: Option<ScheduleStatus>,
+    #[serde(rename = "autodelete", skip_serializing_if = "Option::is_none")]
+    value_auto_delete: Option<bool>,
+}
+
+impl ScheduleChange {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no value was set (every value is `None`)
+    pub fn is_empty(&self) -> bool {
+        self.value_name.is_none()
+            && self.value_description.is_none()
+            && self.value_command.is_none()
+            && self.value_local_time.is_none()
+            && self.value_status.is_none()
+            && self.value_auto_delete.is_none()
+    }
+
+    pub fn name(mut self, name: String) -> Self {
+        self.value_name = Some(name);
+        self
+    }
+
+    pub fn description(mut self, description: String) -> Self {
+        self.value_description = Some(description);
+        self
+    }
+
+    pub fn command(mut self, command: ScheduleCommand) -> Self {
+        self.value_command = Some(command);
+        self
+    }
+
+    pub fn local_time(mut self, local_time: HueTimePattern) -> Self {
+        self.value_local_time = Some(local_time);
+        self
+    }
+
+    pub fn status(mut self, status: ScheduleStatus) -> Self {
+        self.value_status = Some(status);
+        self
+    }
+
+    pub fn auto_delete(mut self, auto_delete: bool) -> Self {
+        self.value_auto_delete = Some(auto_delete);
+        self
+    }
+}
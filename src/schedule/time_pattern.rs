@@ -0,0 +1,487 @@
+use std::{fmt, ops::BitOr, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::HueError;
+
+/// A time of the day or a duration like `07:00:00`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HueTime {
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: u8,
+}
+
+/// A date and a time like `2026-10-18T07:00:00`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HueDateTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub time: HueTime,
+}
+
+/// A bitmask of weekdays (`0MTWTFSS`) used by recurring time patterns
+/// ```
+/// use philipshue::schedule::Weekdays;
+///
+/// let weekdays = Weekdays::MONDAY | Weekdays::FRIDAY;
+/// assert!(weekdays.contains(Weekdays::MONDAY));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Weekdays(pub u8);
+
+impl Weekdays {
+    pub const MONDAY: Self = Self(64);
+    pub const TUESDAY: Self = Self(32);
+    pub const WEDNESDAY: Self = Self(16);
+    pub const THURSDAY: Self = Self(8);
+    pub const FRIDAY: Self = Self(4);
+    pub const SATURDAY: Self = Self(2);
+    pub const SUNDAY: Self = Self(1);
+    /// Monday to friday
+    pub const WORKING_DAYS: Self = Self(124);
+    /// Saturday and sunday
+    pub const WEEKEND: Self = Self(3);
+    pub const ALL: Self = Self(127);
+
+    /// Returns `true` if all days of `other` are part of these weekdays
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Weekdays {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// The time patterns the hue bridge uses for schedules.
+/// Every pattern except the interval can be randomized: the bridge adds a random time between `00:00:00` and `random` to the time.
+/// ```
+/// use philipshue::schedule::{HueTime, HueTimePattern, Weekdays};
+///
+/// let pattern: HueTimePattern = "W127/T07:00:00".parse().unwrap();
+/// assert_eq!(
+///     pattern,
+///     HueTimePattern::Recurring {
+///         weekdays: Weekdays::ALL,
+///         time: HueTime { hours: 7, minutes: 0, seconds: 0 },
+///         random: None,
+///     }
+/// );
+/// assert_eq!(pattern.to_string(), "W127/T07:00:00");
+/// ```
+/// Patterns of the bridge this crate doesn't know are deserialized as `HueTimePattern::Other`, parsing them with `str::parse` fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HueTimePattern {
+    /// `[YYYY]-[MM]-[DD]T[hh]:[mm]:[ss]` or `[YYYY]-[MM]-[DD]T[hh]:[mm]:[ss]A[hh]:[mm]:[ss]`
+    Absolute {
+        time: HueDateTime,
+        random: Option<HueTime>,
+    },
+    /// `W[bbb]/T[hh]:[mm]:[ss]` or `W[bbb]/T[hh]:[mm]:[ss]A[hh]:[mm]:[ss]`
+    Recurring {
+        weekdays: Weekdays,
+        time: HueTime,
+        random: Option<HueTime>,
+    },
+    /// `PT[hh]:[mm]:[ss]` or `PT[hh]:[mm]:[ss]A[hh]:[mm]:[ss]`
+    Timer {
+        duration: HueTime,
+        random: Option<HueTime>,
+    },
+    /// `R[nn]/PT[hh]:[mm]:[ss]` or `R[nn]/PT[hh]:[mm]:[ss]A[hh]:[mm]:[ss]`, the timer runs forever if `occurrences` is `None`
+    RecurringTimer {
+        occurrences: Option<u8>,
+        duration: HueTime,
+        random: Option<HueTime>,
+    },
+    /// `T[hh]:[mm]:[ss]/T[hh]:[mm]:[ss]` or `W[bbb]/T[hh]:[mm]:[ss]/T[hh]:[mm]:[ss]`, the interval is active every day if `weekdays` is `None`
+    Interval {
+        weekdays: Option<Weekdays>,
+        start: HueTime,
+        end: HueTime,
+    },
+    /// A pattern this crate doesn't know, it's kept as the bridge sent it
+    Other(String),
+}
+
+fn invalid(pattern: &str) -> HueError {
+    HueError::InvalidTimePattern(pattern.to_string())
+}
+
+fn parse_number<T: FromStr>(value: &str, pattern: &str) -> Result<T, HueError> {
+    if value.is_empty() || !value.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid(pattern));
+    }
+    value.parse().map_err(|_| invalid(pattern))
+}
+
+impl HueTime {
+    fn parse(value: &str, pattern: &str) -> Result<Self, HueError> {
+        let mut parts = value.split(':');
+        let (Some(hours), Some(minutes), Some(seconds), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid(pattern));
+        };
+        let time = Self {
+            hours: parse_number(hours, pattern)?,
+            minutes: parse_number(minutes, pattern)?,
+            seconds: parse_number(seconds, pattern)?,
+        };
+        if time.hours > 23 || time.minutes > 59 || time.seconds > 59 {
+            return Err(invalid(pattern));
+        }
+        Ok(time)
+    }
+
+    /// Parse `[hh]:[mm]:[ss]` with an optional `A[hh]:[mm]:[ss]` suffix
+    fn parse_randomized(value: &str, pattern: &str) -> Result<(Self, Option<Self>), HueError> {
+        Ok(match value.split_once('A') {
+            Some((time, random)) => (
+                Self::parse(time, pattern)?,
+                Some(Self::parse(random, pattern)?),
+            ),
+            None => (Self::parse(value, pattern)?, None),
+        })
+    }
+}
+
+impl HueDateTime {
+    fn parse(value: &str, pattern: &str) -> Result<Self, HueError> {
+        let (date, time) = value.split_once('T').ok_or_else(|| invalid(pattern))?;
+        let mut parts = date.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid(pattern));
+        };
+        let date_time = Self {
+            year: parse_number(year, pattern)?,
+            month: parse_number(month, pattern)?,
+            day: parse_number(day, pattern)?,
+            time: HueTime::parse(time, pattern)?,
+        };
+        if !(1..=12).contains(&date_time.month) || !(1..=31).contains(&date_time.day) {
+            return Err(invalid(pattern));
+        }
+        Ok(date_time)
+    }
+}
+
+impl FromStr for HueTimePattern {
+    type Err = HueError;
+
+    /// Parse a time pattern, a `HueError::InvalidTimePattern` error is returned if the pattern is invalid
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        if let Some(value) = pattern.strip_prefix("PT") {
+            let (duration, random) = HueTime::parse_randomized(value, pattern)?;
+            Ok(Self::Timer { duration, random })
+        } else if let Some(value) = pattern.strip_prefix('R') {
+            let (occurrences, value) = value.split_once("/PT").ok_or_else(|| invalid(pattern))?;
+            let occurrences = match occurrences {
+                "" => None,
+                occurrences => Some(parse_number(occurrences, pattern)?),
+            };
+            let (duration, random) = HueTime::parse_randomized(value, pattern)?;
+            Ok(Self::RecurringTimer {
+                occurrences,
+                duration,
+                random,
+            })
+        } else if let Some(value) = pattern.strip_prefix('T') {
+            let (start, end) = value.split_once("/T").ok_or_else(|| invalid(pattern))?;
+            Ok(Self::Interval {
+                weekdays: None,
+                start: HueTime::parse(start, pattern)?,
+                end: HueTime::parse(end, pattern)?,
+            })
+        } else if let Some(value) = pattern.strip_prefix('W') {
+            let (weekdays, value) = value.split_once("/T").ok_or_else(|| invalid(pattern))?;
+            let weekdays = parse_number(weekdays, pattern)?;
+            if weekdays > Weekdays::ALL.0 {
+                return Err(invalid(pattern));
+            }
+            if let Some((start, end)) = value.split_once("/T") {
+                return Ok(Self::Interval {
+                    weekdays: Some(Weekdays(weekdays)),
+                    start: HueTime::parse(start, pattern)?,
+                    end: HueTime::parse(end, pattern)?,
+                });
+            }
+            let (time, random) = HueTime::parse_randomized(value, pattern)?;
+            Ok(Self::Recurring {
+                weekdays: Weekdays(weekdays),
+                time,
+                random,
+            })
+        } else {
+            let (time, random) = match pattern.split_once('A') {
+                Some((time, random)) => (time, Some(HueTime::parse(random, pattern)?)),
+                None => (pattern, None),
+            };
+            Ok(Self::Absolute {
+                time: HueDateTime::parse(time, pattern)?,
+                random,
+            })
+        }
+    }
+}
+
+impl fmt::Display for HueTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.hours, self.minutes, self.seconds
+        )
+    }
+}
+
+impl fmt::Display for HueDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{}",
+            self.year, self.month, self.day, self.time
+        )
+    }
+}
+
+impl fmt::Display for HueTimePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let random = match self {
+            Self::Absolute { time, random } => {
+                write!(f, "{time}")?;
+                random
+            }
+            Self::Recurring {
+                weekdays,
+                time,
+                random,
+            } => {
+                write!(f, "W{:03}/T{time}", weekdays.0)?;
+                random
+            }
+            Self::Timer { duration, random } => {
+                write!(f, "PT{duration}")?;
+                random
+            }
+            Self::RecurringTimer {
+                occurrences,
+                duration,
+                random,
+            } => {
+                write!(f, "R")?;
+                if let Some(occurrences) = occurrences {
+                    write!(f, "{occurrences:02}")?;
+                }
+                write!(f, "/PT{duration}")?;
+                random
+            }
+            Self::Interval {
+                weekdays,
+                start,
+                end,
+            } => {
+                if let Some(weekdays) = weekdays {
+                    write!(f, "W{:03}/", weekdays.0)?;
+                }
+                return write!(f, "T{start}/T{end}");
+            }
+            Self::Other(pattern) => return write!(f, "{pattern}"),
+        };
+        if let Some(random) = random {
+            write!(f, "A{random}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for HueTimePattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for HueTimePattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let pattern = String::deserialize(deserializer)?;
+        Ok(pattern.parse().unwrap_or(Self::Other(pattern)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hours: u8, minutes: u8, seconds: u8) -> HueTime {
+        HueTime {
+            hours,
+            minutes,
+            seconds,
+        }
+    }
+
+    fn assert_round_trip(pattern: &str, expected: HueTimePattern) {
+        let parsed: HueTimePattern = pattern.parse().unwrap();
+        assert_eq!(parsed, expected);
+        assert_eq!(parsed.to_string(), pattern);
+    }
+
+    #[test]
+    fn absolute() {
+        let date_time = HueDateTime {
+            year: 2026,
+            month: 10,
+            day: 18,
+            time: time(7, 30, 0),
+        };
+        assert_round_trip(
+            "2026-10-18T07:30:00",
+            HueTimePattern::Absolute {
+                time: date_time,
+                random: None,
+            },
+        );
+        assert_round_trip(
+            "2026-10-18T07:30:00A00:15:00",
+            HueTimePattern::Absolute {
+                time: date_time,
+                random: Some(time(0, 15, 0)),
+            },
+        );
+    }
+
+    #[test]
+    fn recurring() {
+        assert_round_trip(
+            "W124/T07:00:00",
+            HueTimePattern::Recurring {
+                weekdays: Weekdays::WORKING_DAYS,
+                time: time(7, 0, 0),
+                random: None,
+            },
+        );
+        assert_round_trip(
+            "W003/T09:00:00A00:30:00",
+            HueTimePattern::Recurring {
+                weekdays: Weekdays::WEEKEND,
+                time: time(9, 0, 0),
+                random: Some(time(0, 30, 0)),
+            },
+        );
+    }
+
+    #[test]
+    fn timer() {
+        assert_round_trip(
+            "PT00:10:00",
+            HueTimePattern::Timer {
+                duration: time(0, 10, 0),
+                random: None,
+            },
+        );
+        assert_round_trip(
+            "PT00:10:00A00:01:00",
+            HueTimePattern::Timer {
+                duration: time(0, 10, 0),
+                random: Some(time(0, 1, 0)),
+            },
+        );
+    }
+
+    #[test]
+    fn recurring_timer() {
+        assert_round_trip(
+            "R05/PT00:01:00",
+            HueTimePattern::RecurringTimer {
+                occurrences: Some(5),
+                duration: time(0, 1, 0),
+                random: None,
+            },
+        );
+        assert_round_trip(
+            "R/PT00:01:00A00:00:30",
+            HueTimePattern::RecurringTimer {
+                occurrences: None,
+                duration: time(0, 1, 0),
+                random: Some(time(0, 0, 30)),
+            },
+        );
+    }
+
+    #[test]
+    fn interval() {
+        assert_round_trip(
+            "T08:00:00/T09:00:00",
+            HueTimePattern::Interval {
+                weekdays: None,
+                start: time(8, 0, 0),
+                end: time(9, 0, 0),
+            },
+        );
+        assert_round_trip(
+            "W127/T22:00:00/T06:00:00",
+            HueTimePattern::Interval {
+                weekdays: Some(Weekdays::ALL),
+                start: time(22, 0, 0),
+                end: time(6, 0, 0),
+            },
+        );
+    }
+
+    #[test]
+    fn invalid_patterns() {
+        for pattern in [
+            "",
+            "07:00:00",
+            "W128/T07:00:00",
+            "W127/07:00:00",
+            "PT24:00:00",
+            "PT00:60:00",
+            "R5PT00:01:00",
+            "2026-13-01T07:00:00",
+            "2026-10-18T07:00",
+            "T08:00:00",
+            "T08:00:00/T09:00",
+            "PT00:10:00A",
+        ] {
+            assert!(
+                matches!(
+                    pattern.parse::<HueTimePattern>(),
+                    Err(HueError::InvalidTimePattern(_))
+                ),
+                "{pattern} should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn serde_uses_the_string_form() {
+        let pattern: HueTimePattern = serde_json::from_str("\"W127/T08:00:00/T09:00:00\"").unwrap();
+        assert_eq!(
+            serde_json::to_string(&pattern).unwrap(),
+            "\"W127/T08:00:00/T09:00:00\""
+        );
+    }
+
+    #[test]
+    fn unknown_patterns_of_the_bridge_are_kept() {
+        let pattern: HueTimePattern = serde_json::from_str("\"W127/T08:00\"").unwrap();
+        assert_eq!(pattern, HueTimePattern::Other("W127/T08:00".to_string()));
+        assert_eq!(serde_json::to_string(&pattern).unwrap(), "\"W127/T08:00\"");
+        assert!("W127/T08:00".parse::<HueTimePattern>().is_err());
+    }
+}
@@ -0,0 +1,290 @@
+use std::{collections::HashMap, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::HueError, light::HueLight, sensor::HueSensor, Hue};
+
+/// The bridge searches for about 40 seconds, polling more often isn't useful
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Long enough for a search of the bridge but a bridge that stays `active` doesn't block forever
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Serialize)]
+struct SearchRequest {
+    #[serde(rename = "deviceid", skip_serializing_if = "Option::is_none")]
+    device_ids: Option<Vec<String>>,
+}
+
+/// The status of the last search for new devices
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum LastScan {
+    /// The bridge has never searched for new devices
+    None,
+    /// The bridge is currently searching for new devices
+    Active,
+    /// The time the last search was finished
+    Finished(String),
+}
+
+impl From<String> for LastScan {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "none" => Self::None,
+            "active" => Self::Active,
+            _ => Self::Finished(value),
+        }
+    }
+}
+
+/// The devices the bridge found during the last search
+#[derive(Deserialize)]
+pub struct NewDevices {
+    #[serde(rename = "lastscan")]
+    pub last_scan: LastScan,
+    /// The key of the `HashMap` identifies the id of the new device
+    #[serde(flatten)]
+    pub devices: HashMap<String, NewDevice>,
+}
+
+#[derive(Deserialize)]
+pub struct NewDevice {
+    pub name: String,
+}
+
+/// Configure how `Hue::wait_for_new_lights` and `Hue::wait_for_new_sensors` wait for the end of the search
+/// ```
+/// use std::time::Duration;
+/// use philipshue::search::SearchOptions;
+///
+/// let options = SearchOptions::new()
+///     .poll_interval(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(90));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SearchOptions {
+    poll_interval: Duration,
+    timeout: Duration,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+impl SearchOptions {
+    /// Poll every 2 seconds for 60 seconds
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The time between two requests for the new devices
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// The time after which waiting fails with `HueError::SearchTimeout`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl Hue {
+    /// Start a search for new lights. The bridge searches for about 40 seconds, use `Hue::new_lights` to get the result.
+    /// If the lights aren't found automatically you can pass the serial numbers of the lights as `device_ids`
+    /// ```
+    /// use philipshue::{search::SearchOptions, Hue, HueBridge};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string());
+    ///     hue.search_new_lights(None).await.unwrap();
+    ///     let lights = hue.wait_for_new_lights(SearchOptions::new()).await.unwrap();
+    ///     for (light_number, light) in lights {
+    ///         println!("Found light ({light_number}) {}", light.name);
+    ///     }
+    /// }
+    /// ```
+    pub async fn search_new_lights(&self, device_ids: Option<Vec<String>>) -> Result<(), HueError> {
+        self.search(device_ids, "lights").await
+    }
+
+    /// Start a search for new sensors. The bridge searches for about 40 seconds, use `Hue::new_sensors` to get the result
+    pub async fn search_new_sensors(
+        &self,
+        device_ids: Option<Vec<String>>,
+    ) -> Result<(), HueError> {
+        self.search(device_ids, "sensors").await
+    }
+
+    /// Get the lights the bridge found during the last search
+    pub async fn new_lights(&self) -> Result<NewDevices, HueError> {
+        self.new_devices("lights").await
+    }
+
+    /// Get the sensors the bridge found during the last search
+    pub async fn new_sensors(&self) -> Result<NewDevices, HueError> {
+        self.new_devices("sensors").await
+    }
+
+    /// Poll `Hue::new_lights` until the search is finished and get the new lights.
+    /// A `HueError::SearchTimeout` error is thrown if the search isn't finished within the timeout of the `SearchOptions`
+    pub async fn wait_for_new_lights(
+        &self,
+        options: SearchOptions,
+    ) -> Result<HashMap<String, HueLight>, HueError> {
+        let mut lights = HashMap::new();
+        for light_number in self.wait_for_new_devices("lights", options).await? {
+            let light = self.get_light(&light_number).await?;
+            lights.insert(light_number, light);
+        }
+        Ok(lights)
+    }
+
+    /// Poll `Hue::new_sensors` until the search is finished and get the new sensors.
+    /// A `HueError::SearchTimeout` error is thrown if the search isn't finished within the timeout of the `SearchOptions`
+    pub async fn wait_for_new_sensors(
+        &self,
+        options: SearchOptions,
+    ) -> Result<HashMap<String, HueSensor>, HueError> {
+        let mut sensors = HashMap::new();
+        for sensor_number in self.wait_for_new_devices("sensors", options).await? {
+            let sensor = self.get_sensor(&sensor_number).await?;
+            sensors.insert(sensor_number, sensor);
+        }
+        Ok(sensors)
+    }
+
+    async fn search(&self, device_ids: Option<Vec<String>>, path: &str) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .post(format!("{}/{path}", self.get_username_url()))
+                    .json(&SearchRequest { device_ids })
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn new_devices(&self, path: &str) -> Result<NewDevices, HueError> {
+        Ok(self
+            .bridge
+            .client()
+            .get(format!("{}/{path}/new", self.get_username_url()))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// Returns the ids of the new devices after the search is finished
+    async fn wait_for_new_devices(
+        &self,
+        path: &str,
+        options: SearchOptions,
+    ) -> Result<Vec<String>, HueError> {
+        let wait = async {
+            loop {
+                let new_devices = self.new_devices(path).await?;
+                if new_devices.last_scan != LastScan::Active {
+                    return Ok(new_devices.devices.into_keys().collect());
+                }
+                tokio::time::sleep(options.poll_interval).await;
+            }
+        };
+        tokio::time::timeout(options.timeout, wait)
+            .await
+            .map_err(|_| HueError::SearchTimeout)?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+    use crate::HueBridge;
+
+    #[test]
+    fn last_scan() {
+        let parse = |value: &str| serde_json::from_value::<LastScan>(value.into()).unwrap();
+        assert_eq!(parse("none"), LastScan::None);
+        assert_eq!(parse("active"), LastScan::Active);
+        assert_eq!(
+            parse("2012-10-29T12:00:00"),
+            LastScan::Finished("2012-10-29T12:00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn new_devices() {
+        let new_devices: NewDevices = serde_json::from_str(
+            r#"{"7": {"name": "Hue Lamp 7"}, "8": {"name": "Hue Lamp 8"}, "lastscan": "2012-10-29T12:00:00"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            new_devices.last_scan,
+            LastScan::Finished("2012-10-29T12:00:00".to_string())
+        );
+        assert_eq!(new_devices.devices.len(), 2);
+        assert_eq!(new_devices.devices["7"].name, "Hue Lamp 7");
+        assert_eq!(new_devices.devices["8"].name, "Hue Lamp 8");
+
+        let new_devices: NewDevices = serde_json::from_str(r#"{"lastscan": "active"}"#).unwrap();
+        assert_eq!(new_devices.last_scan, LastScan::Active);
+        assert!(new_devices.devices.is_empty());
+    }
+
+    #[tokio::test]
+    async fn waiting_times_out_while_the_search_is_active() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let hue = Hue::new(
+            HueBridge::new(format!("http://{}", listener.local_addr().unwrap())),
+            "username".to_string(),
+        );
+        // the bridge never finishes the search
+        let server = tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buffer = [0; 1024];
+                let _ = socket.read(&mut buffer).await;
+                let body = r#"{"lastscan": "active"}"#;
+                let _ = socket
+                    .write_all(
+                        format!(
+                            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                            body.len()
+                        )
+                        .as_bytes(),
+                    )
+                    .await;
+            }
+        });
+        let start = Instant::now();
+        let result = hue
+            .wait_for_new_lights(
+                SearchOptions::new()
+                    .poll_interval(Duration::from_millis(10))
+                    .timeout(Duration::from_millis(200)),
+            )
+            .await;
+        assert!(matches!(result, Err(HueError::SearchTimeout)));
+        assert!(start.elapsed() < Duration::from_secs(5));
+        server.abort();
+    }
+}
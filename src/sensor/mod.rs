@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use crate::{error::HueError, Hue};
+
+mod model;
+mod sensor_change;
+
+pub use model::*;
+pub use sensor_change::*;
+
+impl Hue {
+    /// Get a `HashMap` of all sensors the hue bridge know. The key of the `HashMap` identifies the id of the `HueSensor`
+    pub async fn sensors(&self) -> Result<HashMap<String, HueSensor>, HueError> {
+        Ok(self
+            .bridge
+            .client()
+            .get(format!("{}/sensors", self.get_username_url()))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// Get one specific sensor from it's `sensor_number`
+    pub async fn get_sensor(&self, sensor_number: &str) -> Result<HueSensor, HueError> {
+        Ok(self
+            .bridge
+            .client()
+            .get(format!(
+                "{}/sensors/{sensor_number}",
+                self.get_username_url()
+            ))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// Delete a sensor
+    pub async fn delete_sensor(&self, sensor_number: &str) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .delete(format!(
+                        "{}/sensors/{sensor_number}",
+                        self.get_username_url()
+                    ))
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+use serde::{Deserialize, Deserializer};
+
+/// The attributes all sensors have in common. `S` is the type of the `state` and `C` the type of the `config` of the sensor
+#[derive(Deserialize)]
+pub struct SensorData<S, C> {
+    pub name: String,
+    #[serde(rename = "modelid")]
+    pub model_id: String,
+    #[serde(rename = "manufacturername")]
+    pub manufacturer_name: String,
+    #[serde(rename = "productname")]
+    pub product_name: Option<String>,
+    #[serde(rename = "swversion")]
+    pub software_version: Option<String>,
+    #[serde(rename = "uniqueid")]
+    pub unique_id: Option<String>,
+    pub recycle: Option<bool>,
+    pub state: S,
+    pub config: C,
+}
+
+macro_rules! hue_sensors {
+    ($($(#[$meta:meta])* $variant:ident($state:ty, $config:ty),)*) => {
+        /// A sensor the hue bridge knows. The variant is chosen by the `type` of the sensor.
+        /// Sensors with a type this crate doesn't know are returned as `HueSensor::Unknown`
+        #[allow(clippy::upper_case_acronyms)]
+        pub enum HueSensor {
+            $($(#[$meta])* $variant(SensorData<$state, $config>),)*
+            Unknown(serde_json::Value),
+            /// A sensor of a known type that couldn't be deserialized, `error` is the reason
+            Invalid {
+                error: String,
+                value: serde_json::Value,
+            },
+        }
+
+        impl HueSensor {
+            /// The `type` of the sensor like `ZLLPresence`
+            pub fn sensor_type(&self) -> &str {
+                match self {
+                    $(Self::$variant(_) => stringify!($variant),)*
+                    Self::Unknown(value) | Self::Invalid { value, .. } => value
+                        .get("type")
+                        .and_then(|sensor_type| sensor_type.as_str())
+                        .unwrap_or_default(),
+                }
+            }
+
+            pub fn name(&self) -> &str {
+                match self {
+                    $(Self::$variant(sensor) => &sensor.name,)*
+                    Self::Unknown(value) | Self::Invalid { value, .. } => value
+                        .get("name")
+                        .and_then(|name| name.as_str())
+                        .unwrap_or_default(),
+                }
+            }
+        }
+
+        /// The sensor is deserialized depending on it's `type`, if the type is unknown `HueSensor::Unknown` is used.
+        /// A sensor of a known type that couldn't be deserialized is returned as `HueSensor::Invalid`, so one sensor doesn't break all others
+        impl<'de> Deserialize<'de> for HueSensor {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let value = serde_json::Value::deserialize(deserializer)?;
+                let sensor = match value.get("type").and_then(|sensor_type| sensor_type.as_str()) {
+                    $(Some(stringify!($variant)) => SensorData::deserialize(&value).map(Self::$variant),)*
+                    _ => return Ok(Self::Unknown(value)),
+                };
+                Ok(sensor.unwrap_or_else(|e| Self::Invalid {
+                    error: e.to_string(),
+                    value,
+                }))
+            }
+        }
+    };
+}
+
+hue_sensors! {
+    /// A hue motion sensor
+    ZLLPresence(PresenceState, PresenceConfig),
+    ZHAPresence(PresenceState, PresenceConfig),
+    CLIPPresence(PresenceState, SensorConfig),
+    /// The temperature sensor of a hue motion sensor
+    ZLLTemperature(TemperatureState, SensorConfig),
+    CLIPTemperature(TemperatureState, SensorConfig),
+    /// The light level sensor of a hue motion sensor
+    ZLLLightLevel(LightLevelState, LightLevelConfig),
+    CLIPLightLevel(LightLevelState, LightLevelConfig),
+    /// A hue dimmer switch or the buttons of a hue tap dial
+    ZLLSwitch(SwitchState, SensorConfig),
+    /// A hue tap switch or a friends of hue switch
+    ZGPSwitch(SwitchState, SensorConfig),
+    CLIPSwitch(SwitchState, SensorConfig),
+    /// The rotary dial of a hue tap dial
+    ZLLRelativeRotary(RelativeRotaryState, SensorConfig),
+    /// The daylight sensor that is built into the bridge
+    Daylight(DaylightState, DaylightConfig),
+    CLIPGenericStatus(GenericStatusState, SensorConfig),
+    CLIPGenericFlag(GenericFlagState, SensorConfig),
+    CLIPHumidity(HumidityState, SensorConfig),
+    CLIPOpenClose(OpenCloseState, SensorConfig),
+}
+
+#[derive(Deserialize)]
+pub struct SensorConfig {
+    pub on: bool,
+    pub battery: Option<u8>,
+    pub reachable: Option<bool>,
+    pub alert: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct PresenceConfig {
+    pub on: bool,
+    pub battery: Option<u8>,
+    pub reachable: Option<bool>,
+    pub alert: Option<String>,
+    pub sensitivity: Option<u8>,
+    #[serde(rename = "sensitivitymax")]
+    pub sensitivity_max: Option<u8>,
+    #[serde(rename = "ledindication")]
+    pub led_indication: Option<bool>,
+    #[serde(rename = "usertest")]
+    pub user_test: Option<bool>,
+}
+
+#[derive(Deserialize)]
+pub struct LightLevelConfig {
+    pub on: bool,
+    pub battery: Option<u8>,
+    pub reachable: Option<bool>,
+    pub alert: Option<String>,
+    /// The light level below which `LightLevelState::dark` is `true`
+    #[serde(rename = "tholddark")]
+    pub threshold_dark: Option<u16>,
+    /// The offset to `threshold_dark` above which `LightLevelState::daylight` is `true`
+    #[serde(rename = "tholdoffset")]
+    pub threshold_offset: Option<u16>,
+    #[serde(rename = "ledindication")]
+    pub led_indication: Option<bool>,
+    #[serde(rename = "usertest")]
+    pub user_test: Option<bool>,
+}
+
+#[derive(Deserialize)]
+pub struct DaylightConfig {
+    pub on: bool,
+    /// `true` if the location (latitude and longitude) of the bridge is configured
+    pub configured: bool,
+    /// Offset in minutes
+    #[serde(rename = "sunriseoffset")]
+    pub sunrise_offset: Option<i8>,
+    /// Offset in minutes
+    #[serde(rename = "sunsetoffset")]
+    pub sunset_offset: Option<i8>,
+}
+
+#[derive(Deserialize)]
+pub struct PresenceState {
+    pub presence: Option<bool>,
+    #[serde(rename = "lastupdated")]
+    pub last_updated: String,
+}
+
+#[derive(Deserialize)]
+pub struct TemperatureState {
+    /// The temperature in 0.01 degrees celsius
+    pub temperature: Option<i32>,
+    #[serde(rename = "lastupdated")]
+    pub last_updated: String,
+}
+
+#[derive(Deserialize)]
+pub struct LightLevelState {
+    /// The light level as `10000 * log10(lux) + 1`
+    #[serde(rename = "lightlevel")]
+    pub light_level: Option<u32>,
+    pub dark: Option<bool>,
+    pub daylight: Option<bool>,
+    #[serde(rename = "lastupdated")]
+    pub last_updated: String,
+}
+
+#[derive(Deserialize)]
+pub struct SwitchState {
+    /// The code of the last button event like `1002` (button 1 short released)
+    #[serde(rename = "buttonevent")]
+    pub button_event: Option<u16>,
+    #[serde(rename = "lastupdated")]
+    pub last_updated: String,
+}
+
+#[derive(Deserialize)]
+pub struct RelativeRotaryState {
+    #[serde(rename = "rotaryevent")]
+    pub rotary_event: Option<u8>,
+    #[serde(rename = "expectedrotation")]
+    pub expected_rotation: Option<i32>,
+    #[serde(rename = "expectedeventduration")]
+    pub expected_event_duration: Option<u32>,
+    #[serde(rename = "lastupdated")]
+    pub last_updated: String,
+}
+
+#[derive(Deserialize)]
+pub struct DaylightState {
+    /// `None` if the daylight sensor isn't configured
+    pub daylight: Option<bool>,
+    #[serde(rename = "lastupdated")]
+    pub last_updated: String,
+}
+
+#[derive(Deserialize)]
+pub struct GenericStatusState {
+    pub status: i32,
+    #[serde(rename = "lastupdated")]
+    pub last_updated: String,
+}
+
+#[derive(Deserialize)]
+pub struct GenericFlagState {
+    pub flag: bool,
+    #[serde(rename = "lastupdated")]
+    pub last_updated: String,
+}
+
+#[derive(Deserialize)]
+pub struct HumidityState {
+    /// The relative humidity in 0.01 percent
+    pub humidity: Option<u16>,
+    #[serde(rename = "lastupdated")]
+    pub last_updated: String,
+}
+
+#[derive(Deserialize)]
+pub struct OpenCloseState {
+    pub open: Option<bool>,
+    #[serde(rename = "lastupdated")]
+    pub last_updated: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn known_sensor_type() {
+        let sensor: HueSensor = serde_json::from_value(json!({
+            "state": {"temperature": 2154, "lastupdated": "2023-11-14T22:13:20"},
+            "config": {"on": true, "battery": 100, "reachable": true, "alert": "none"},
+            "name": "Hue temperature sensor 1",
+            "type": "ZLLTemperature",
+            "modelid": "SML001",
+            "manufacturername": "Signify Netherlands B.V.",
+            "productname": "Hue temperature sensor",
+            "swversion": "6.1.1.27575",
+            "uniqueid": "00:17:88:01:02:03:04:05-02-0402"
+        }))
+        .unwrap();
+        assert_eq!(sensor.sensor_type(), "ZLLTemperature");
+        assert_eq!(sensor.name(), "Hue temperature sensor 1");
+        match sensor {
+            HueSensor::ZLLTemperature(sensor) => {
+                assert_eq!(sensor.state.temperature, Some(2154));
+                assert_eq!(sensor.config.battery, Some(100));
+            }
+            _ => panic!("expected a ZLLTemperature sensor"),
+        }
+    }
+
+    #[test]
+    fn unknown_sensor_type() {
+        let sensor: HueSensor = serde_json::from_value(json!({
+            "state": {"lastupdated": "none"},
+            "config": {"on": true},
+            "name": "Thermostat",
+            "type": "ZHAThermostat",
+            "modelid": "TH001",
+            "manufacturername": "Someone"
+        }))
+        .unwrap();
+        assert!(matches!(sensor, HueSensor::Unknown(_)));
+        assert_eq!(sensor.sensor_type(), "ZHAThermostat");
+        assert_eq!(sensor.name(), "Thermostat");
+    }
+
+    #[test]
+    fn malformed_known_sensor_type() {
+        let sensor: HueSensor = serde_json::from_value(json!({
+            "state": {"presence": "yes", "lastupdated": "2023-11-14T22:13:20"},
+            "config": {"on": true},
+            "name": "Hue motion sensor 1",
+            "type": "ZLLPresence",
+            "modelid": "SML001",
+            "manufacturername": "Signify Netherlands B.V."
+        }))
+        .unwrap();
+        match &sensor {
+            HueSensor::Invalid { error, .. } => assert!(error.contains("invalid type")),
+            _ => panic!("expected an invalid sensor"),
+        }
+        assert_eq!(sensor.sensor_type(), "ZLLPresence");
+        assert_eq!(sensor.name(), "Hue motion sensor 1");
+    }
+}
@@ -0,0 +1,381 @@
+use serde::Serialize;
+
+use crate::{bridge::CreationResponse, error::HueError, Hue};
+
+impl Hue {
+    /// Create a new (CLIP) sensor and return the id of the created sensor
+    /// ```
+    /// use philipshue::{Hue, HueBridge, sensor::{NewSensor, SensorStateChange}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string());
+    ///     let sensor_id = hue.create_sensor(
+    ///         NewSensor::new(
+    ///             "Away flag".to_string(),
+    ///             "CLIPGenericFlag".to_string(),
+    ///             "away_flag".to_string(),
+    ///             "1.0".to_string(),
+    ///             "away-flag-1".to_string(),
+    ///             "my_device_name".to_string(),
+    ///         )
+    ///         .state(SensorStateChange::new().flag(false))
+    ///     ).await.unwrap();
+    /// }
+    /// ```
+    pub async fn create_sensor(&self, new_sensor: NewSensor) -> Result<String, HueError> {
+        Ok(self
+            .bridge
+            .parse_response::<CreationResponse>(
+                self.bridge
+                    .client()
+                    .post(format!("{}/sensors", self.get_username_url()))
+                    .json(&new_sensor)
+                    .send()
+                    .await?,
+            )
+            .await?
+            .id)
+    }
+
+    /// Change the config of a sensor
+    pub async fn update_sensor_config(
+        &self,
+        sensor_number: &str,
+        config_change: SensorConfigChange,
+    ) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .put(format!(
+                        "{}/sensors/{sensor_number}/config",
+                        self.get_username_url()
+                    ))
+                    .json(&config_change)
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Change the state of a sensor, this is only possible for CLIP sensors
+    pub async fn update_sensor_state(
+        &self,
+        sensor_number: &str,
+        state_change: SensorStateChange,
+    ) -> Result<(), HueError> {
+        self.bridge
+            .parse_response::<serde_json::Value>(
+                self.bridge
+                    .client()
+                    .put(format!(
+                        "{}/sensors/{sensor_number}/state",
+                        self.get_username_url()
+                    ))
+                    .json(&state_change)
+                    .send()
+                    .await?,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Build a `NewSensor` object to create a CLIP sensor. The `sensor_type` is something like `CLIPGenericFlag` or `CLIPPresence`
+#[derive(Clone, Serialize)]
+pub struct NewSensor {
+    name: String,
+    #[serde(rename = "type")]
+    sensor_type: String,
+    #[serde(rename = "modelid")]
+    model_id: String,
+    #[serde(rename = "swversion")]
+    software_version: String,
+    #[serde(rename = "uniqueid")]
+    unique_id: String,
+    #[serde(rename = "manufacturername")]
+    manufacturer_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recycle: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<SensorStateChange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config: Option<SensorConfigChange>,
+}
+
+impl NewSensor {
+    pub fn new(
+        name: String,
+        sensor_type: String,
+        model_id: String,
+        software_version: String,
+        unique_id: String,
+        manufacturer_name: String,
+    ) -> Self {
+        Self {
+            name,
+            sensor_type,
+            model_id,
+            software_version,
+            unique_id,
+            manufacturer_name,
+            recycle: None,
+            state: None,
+            config: None,
+        }
+    }
+
+    /// If `recycle` is `true` the bridge may delete the sensor if it's no longer used by a rule
+    pub fn recycle(mut self, recycle: bool) -> Self {
+        self.recycle = Some(recycle);
+        self
+    }
+
+    /// The initial state of the sensor
+    pub fn state(mut self, state: SensorStateChange) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// The initial config of the sensor
+    pub fn config(mut self, config: SensorConfigChange) -> Self {
+        self.config = Some(config);
+        self
+    }
+}
+
+/// Build `SensorConfigChange` objects to change the config of a `HueSensor`. Not every sensor type supports every value
+/// ```
+/// use philipshue::sensor::SensorConfigChange;
+///
+/// let change = SensorConfigChange::new()
+///     .on(true) // enable the sensor
+///     .sensitivity(2); // set the sensitivity of a motion sensor
+/// ```
+#[derive(Default, Clone, Serialize)]
+pub struct SensorConfigChange {
+    #[serde(rename = "on", skip_serializing_if = "Option::is_none")]
+    value_on: Option<bool>,
+    #[serde(rename = "reachable", skip_serializing_if = "Option::is_none")]
+    value_reachable: Option<bool>,
+    #[serde(rename = "battery", skip_serializing_if = "Option::is_none")]
+    value_battery: Option<u8>,
+    #[serde(rename = "alert", skip_serializing_if = "Option::is_none")]
+    value_alert: Option<String>,
+    #[serde(rename = "sensitivity", skip_serializing_if = "Option::is_none")]
+    value_sensitivity: Option<u8>,
+    #[serde(rename = "ledindication", skip_serializing_if = "Option::is_none")]
+    value_led_indication: Option<bool>,
+    #[serde(rename = "usertest", skip_serializing_if = "Option::is_none")]
+    value_user_test: Option<bool>,
+    #[serde(rename = "tholddark", skip_serializing_if = "Option::is_none")]
+    value_threshold_dark: Option<u16>,
+    #[serde(rename = "tholdoffset", skip_serializing_if = "Option::is_none")]
+    value_threshold_offset: Option<u16>,
+    #[serde(rename = "sunriseoffset", skip_serializing_if = "Option::is_none")]
+    value_sunrise_offset: Option<i8>,
+    #[serde(rename = "sunsetoffset", skip_serializing_if = "Option::is_none")]
+    value_sunset_offset: Option<i8>,
+    #[serde(rename = "lat", skip_serializing_if = "Option::is_none")]
+    value_latitude: Option<String>,
+    #[serde(rename = "long", skip_serializing_if = "Option::is_none")]
+    value_longitude: Option<String>,
+}
+
+impl SensorConfigChange {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no value was set (every value is `None`)
+    pub fn is_empty(&self) -> bool {
+        self.value_on.is_none()
+            && self.value_reachable.is_none()
+            && self.value_battery.is_none()
+            && self.value_alert.is_none()
+            && self.value_sensitivity.is_none()
+            && self.value_led_indication.is_none()
+            && self.value_user_test.is_none()
+            && self.value_threshold_dark.is_none()
+            && self.value_threshold_offset.is_none()
+            && self.value_sunrise_offset.is_none()
+            && self.value_sunset_offset.is_none()
+            && self.value_latitude.is_none()
+            && self.value_longitude.is_none()
+    }
+
+    pub fn on(mut self, on: bool) -> Self {
+        self.value_on = Some(on);
+        self
+    }
+
+    /// Only CLIP sensors can change `reachable`
+    pub fn reachable(mut self, reachable: bool) -> Self {
+        self.value_reachable = Some(reachable);
+        self
+    }
+
+    /// Only CLIP sensors can change `battery`
+    pub fn battery(mut self, battery: u8) -> Self {
+        self.value_battery = Some(battery);
+        self
+    }
+
+    pub fn alert(mut self, alert: String) -> Self {
+        self.value_alert = Some(alert);
+        self
+    }
+
+    pub fn sensitivity(mut self, sensitivity: u8) -> Self {
+        self.value_sensitivity = Some(sensitivity);
+        self
+    }
+
+    pub fn led_indication(mut self, led_indication: bool) -> Self {
+        self.value_led_indication = Some(led_indication);
+        self
+    }
+
+    pub fn user_test(mut self, user_test: bool) -> Self {
+        self.value_user_test = Some(user_test);
+        self
+    }
+
+    pub fn threshold_dark(mut self, threshold_dark: u16) -> Self {
+        self.value_threshold_dark = Some(threshold_dark);
+        self
+    }
+
+    pub fn threshold_offset(mut self, threshold_offset: u16) -> Self {
+        self.value_threshold_offset = Some(threshold_offset);
+        self
+    }
+
+    /// The offset in minutes of the daylight sensor
+    pub fn sunrise_offset(mut self, sunrise_offset: i8) -> Self {
+        self.value_sunrise_offset = Some(sunrise_offset);
+        self
+    }
+
+    /// The offset in minutes of the daylight sensor
+    pub fn sunset_offset(mut self, sunset_offset: i8) -> Self {
+        self.value_sunset_offset = Some(sunset_offset);
+        self
+    }
+
+    /// The latitude of the daylight sensor in the format `xxx.xxxxN` or `xxx.xxxxS`
+    pub fn latitude(mut self, latitude: String) -> Self {
+        self.value_latitude = Some(latitude);
+        self
+    }
+
+    /// The longitude of the daylight sensor in the format `xxx.xxxxE` or `xxx.xxxxW`
+    pub fn longitude(mut self, longitude: String) -> Self {
+        self.value_longitude = Some(longitude);
+        self
+    }
+}
+
+/// Build `SensorStateChange` objects to change the state of a CLIP sensor. Only set the values that fit the type of the sensor
+/// ```
+/// use philipshue::sensor::SensorStateChange;
+///
+/// let change = SensorStateChange::new().flag(true);
+/// ```
+#[derive(Default, Clone, Serialize)]
+pub struct SensorStateChange {
+    #[serde(rename = "presence", skip_serializing_if = "Option::is_none")]
+    value_presence: Option<bool>,
+    #[serde(rename = "temperature", skip_serializing_if = "Option::is_none")]
+    value_temperature: Option<i32>,
+    #[serde(rename = "lightlevel", skip_serializing_if = "Option::is_none")]
+    value_light_level: Option<u32>,
+    #[serde(rename = "dark", skip_serializing_if = "Option::is_none")]
+    value_dark: Option<bool>,
+    #[serde(rename = "daylight", skip_serializing_if = "Option::is_none")]
+    value_daylight: Option<bool>,
+    #[serde(rename = "buttonevent", skip_serializing_if = "Option::is_none")]
+    value_button_event: Option<u16>,
+    #[serde(rename = "flag", skip_serializing_if = "Option::is_none")]
+    value_flag: Option<bool>,
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    value_status: Option<i32>,
+    #[serde(rename = "humidity", skip_serializing_if = "Option::is_none")]
+    value_humidity: Option<u16>,
+    #[serde(rename = "open", skip_serializing_if = "Option::is_none")]
+    value_open: Option<bool>,
+}
+
+impl SensorStateChange {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no value was set (every value is `None`)
+    pub fn is_empty(&self) -> bool {
+        self.value_presence.is_none()
+            && self.value_temperature.is_none()
+            && self.value_light_level.is_none()
+            && self.value_dark.is_none()
+            && self.value_daylight.is_none()
+            && self.value_button_event.is_none()
+            && self.value_flag.is_none()
+            && self.value_status.is_none()
+            && self.value_humidity.is_none()
+            && self.value_open.is_none()
+    }
+
+    pub fn presence(mut self, presence: bool) -> Self {
+        self.value_presence = Some(presence);
+        self
+    }
+
+    /// The temperature in 0.01 degrees celsius
+    pub fn temperature(mut self, temperature: i32) -> Self {
+        self.value_temperature = Some(temperature);
+        self
+    }
+
+    pub fn light_level(mut self, light_level: u32) -> Self {
+        self.value_light_level = Some(light_level);
+        self
+    }
+
+    pub fn dark(mut self, dark: bool) -> Self {
+        self.value_dark = Some(dark);
+        self
+    }
+
+    pub fn daylight(mut self, daylight: bool) -> Self {
+        self.value_daylight = Some(daylight);
+        self
+    }
+
+    pub fn button_event(mut self, button_event: u16) -> Self {
+        self.value_button_event = Some(button_event);
+        self
+    }
+
+    pub fn flag(mut self, flag: bool) -> Self {
+        self.value_flag = Some(flag);
+        self
+    }
+
+    pub fn status(mut self, status: i32) -> Self {
+        self.value_status = Some(status);
+        self
+    }
+
+    /// The relative humidity in 0.01 percent
+    pub fn humidity(mut self, humidity: u16) -> Self {
+        self.value_humidity = Some(humidity);
+        self
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.value_open = Some(open);
+        self
+    }
+}
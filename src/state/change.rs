@@ -0,0 +1,110 @@
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+
+/// The kind of a resource in the `BridgeState`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceKind {
+    Light,
+    Group,
+    Scene,
+    Sensor,
+}
+
+impl ResourceKind {
+    /// The path of the resources in the v1 api like `lights`
+    pub fn path(&self) -> &'static str {
+        match self {
+            Self::Light => "lights",
+            Self::Group => "groups",
+            Self::Scene => "scenes",
+            Self::Sensor => "sensors",
+        }
+    }
+}
+
+/// Identifies a resource in the `BridgeState` by it's kind and it's id of the v1 api (like the light number)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ResourceKey {
+    pub kind: ResourceKind,
+    pub id: String,
+}
+
+impl ResourceKey {
+    pub fn light(light_number: &str) -> Self {
+        Self {
+            kind: ResourceKind::Light,
+            id: light_number.to_string(),
+        }
+    }
+
+    pub fn group(group_number: &str) -> Self {
+        Self {
+            kind: ResourceKind::Group,
+            id: group_number.to_string(),
+        }
+    }
+
+    pub fn scene(scene_id: &str) -> Self {
+        Self {
+            kind: ResourceKind::Scene,
+            id: scene_id.to_string(),
+        }
+    }
+
+    pub fn sensor(sensor_number: &str) -> Self {
+        Self {
+            kind: ResourceKind::Sensor,
+            id: sensor_number.to_string(),
+        }
+    }
+
+    /// Parse the path of the v1 api like `/lights/1` (the `id_v1` of the v2 resources)
+    pub fn from_path(path: &str) -> Option<Self> {
+        let (kind, id) = path.strip_prefix('/')?.split_once('/')?;
+        let kind = match kind {
+            "lights" => ResourceKind::Light,
+            "groups" => ResourceKind::Group,
+            "scenes" => ResourceKind::Scene,
+            "sensors" => ResourceKind::Sensor,
+            _ => return None,
+        };
+        Some(Self {
+            kind,
+            id: id.to_string(),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Updated,
+    Removed,
+}
+
+/// A change of the `BridgeState`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceChange {
+    pub key: ResourceKey,
+    pub kind: ChangeKind,
+}
+
+/// Wait for the changes of one resource, created by `BridgeState::watch`
+pub struct ResourceWatcher {
+    pub(crate) key: ResourceKey,
+    pub(crate) receiver: Receiver<ResourceChange>,
+}
+
+impl ResourceWatcher {
+    /// Wait for the next change of the resource, `None` is returned if the `BridgeState` was dropped.
+    /// If the watcher couldn't keep up with the changes `ChangeKind::Updated` is returned because the resource may have changed
+    pub async fn changed(&mut self) -> Option<ChangeKind> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(change) if change.key == self.key => return Some(change.kind),
+                Ok(_) => {}
+                Err(RecvError::Lagged(_)) => return Some(ChangeKind::Updated),
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+}
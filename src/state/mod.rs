@@ -0,0 +1,126 @@
+//! A local copy of the lights, groups, scenes and sensors of the bridge that is kept in sync with the bridge
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use tokio::sync::broadcast;
+
+use crate::{
+    error::HueError, group::HueGroup, light::HueLight, scene::HueScene, sensor::HueSensor, Hue,
+};
+
+mod change;
+mod store;
+mod sync;
+
+pub use change::*;
+
+use store::Store;
+
+/// The number of changes a subscriber can fall behind before it misses changes
+const CHANGE_CAPACITY: usize = 256;
+
+/// The `BridgeState` holds every light, group, scene and sensor of the bridge, so you can read them without a request to the bridge.
+/// Keep it in sync with `BridgeState::sync_events` (v2 event stream) or `BridgeState::sync_polling` (v1 api)
+/// ```
+/// use philipshue::{Hue, HueBridge, state::{BridgeState, ResourceKey}};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let hue = Hue::new(HueBridge::new("url".to_string()), "username".to_string());
+///     let state = BridgeState::new(hue).await.unwrap();
+///     tokio::spawn({
+///         let state = state.clone();
+///         async move { state.sync_events().await }
+///     });
+///
+///     let mut watcher = state.watch(ResourceKey::light("1"));
+///     while let Some(_) = watcher.changed().await {
+///         if let Some(light) = state.light("1") {
+///             println!("the light is {}", if light.state.on { "on" } else { "off" });
+///         }
+///     }
+/// }
+/// ```
+/// All clones share the same state.
+#[derive(Clone)]
+pub struct BridgeState {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    hue: Hue,
+    store: RwLock<Store>,
+    changes: broadcast::Sender<ResourceChange>,
+}
+
+impl BridgeState {
+    /// Create the state with a snapshot of all resources
+    pub async fn new(hue: Hue) -> Result<Self, HueError> {
+        let state = Self {
+            inner: Arc::new(Inner {
+                hue,
+                store: RwLock::new(Store::default()),
+                changes: broadcast::channel(CHANGE_CAPACITY).0,
+            }),
+        };
+        state.refresh().await?;
+        Ok(state)
+    }
+
+    pub fn light(&self, light_number: &str) -> Option<Arc<HueLight>> {
+        self.inner.store.read().unwrap().lights.get(light_number)
+    }
+
+    pub fn lights(&self) -> HashMap<String, Arc<HueLight>> {
+        self.inner.store.read().unwrap().lights.all()
+    }
+
+    pub fn group(&self, group_number: &str) -> Option<Arc<HueGroup>> {
+        self.inner.store.read().unwrap().groups.get(group_number)
+    }
+
+    pub fn groups(&self) -> HashMap<String, Arc<HueGroup>> {
+        self.inner.store.read().unwrap().groups.all()
+    }
+
+    /// The `light_states` of the scene are always `None`, use `Hue::get_scene` to get them
+    pub fn scene(&self, scene_id: &str) -> Option<Arc<HueScene>> {
+        self.inner.store.read().unwrap().scenes.get(scene_id)
+    }
+
+    pub fn scenes(&self) -> HashMap<String, Arc<HueScene>> {
+        self.inner.store.read().unwrap().scenes.all()
+    }
+
+    pub fn sensor(&self, sensor_number: &str) -> Option<Arc<HueSensor>> {
+        self.inner.store.read().unwrap().sensors.get(sensor_number)
+    }
+
+    pub fn sensors(&self) -> HashMap<String, Arc<HueSensor>> {
+        self.inner.store.read().unwrap().sensors.all()
+    }
+
+    /// Receive every change of the state
+    pub fn subscribe(&self) -> broadcast::Receiver<ResourceChange> {
+        self.inner.changes.subscribe()
+    }
+
+    /// Wait for the changes of one resource
+    pub fn watch(&self, key: ResourceKey) -> ResourceWatcher {
+        ResourceWatcher {
+            key,
+            receiver: self.subscribe(),
+        }
+    }
+
+    /// The changes are sent after the lock of the store was released
+    fn publish(&self, changes: Vec<ResourceChange>) {
+        for change in changes {
+            // sending only fails if there is no subscriber
+            let _ = self.inner.changes.send(change);
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use std::{collections::HashMap, sync::Arc};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    error::HueError, group::HueGroup, light::HueLight, scene::HueScene, sensor::HueSensor,
+};
+
+use super::{ChangeKind, ResourceChange, ResourceKey, ResourceKind};
+
+/// The json of the resource is kept to find out if the resource changed
+struct Entry<T> {
+    raw: serde_json::Value,
+    value: Arc<T>,
+}
+
+pub(crate) struct Collection<T> {
+    entries: HashMap<String, Entry<T>>,
+}
+
+impl<T> Default for Collection<T> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+fn parse<T>(id: &str, raw: &serde_json::Value) -> Result<T, HueError>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(raw).map_err(|e| HueError::InvalidResource(format!("{id}: {e}")))
+}
+
+impl<T> Collection<T>
+where
+    T: DeserializeOwned,
+{
+    pub fn get(&self, id: &str) -> Option<Arc<T>> {
+        Some(self.entries.get(id)?.value.clone())
+    }
+
+    pub fn all(&self) -> HashMap<String, Arc<T>> {
+        self.entries
+            .iter()
+            .map(|(id, entry)| (id.clone(), entry.value.clone()))
+            .collect()
+    }
+
+    /// Returns `None` if the resource didn't change
+    pub fn set(
+        &mut self,
+        id: &str,
+        raw: serde_json::Value,
+    ) -> Result<Option<ChangeKind>, HueError> {
+        let kind = match self.entries.get(id) {
+            Some(entry) if entry.raw == raw => return Ok(None),
+            Some(_) => ChangeKind::Updated,
+            None => ChangeKind::Added,
+        };
+        let value = Arc::new(parse(id, &raw)?);
+        self.entries.insert(id.to_string(), Entry { raw, value });
+        Ok(Some(kind))
+    }
+
+    pub fn remove(&mut self, id: &str) -> Option<ChangeKind> {
+        self.entries.remove(id).map(|_| ChangeKind::Removed)
+    }
+
+    /// Replace all resources. Resources that can't be parsed are left out, so one invalid resource doesn't keep the others from being updated
+    pub fn replace_all(
+        &mut self,
+        resources: HashMap<String, serde_json::Value>,
+        kind: ResourceKind,
+    ) -> Vec<ResourceChange> {
+        let mut entries = HashMap::new();
+        let mut changes = Vec::new();
+        for (id, raw) in resources {
+            let entry = match self.entries.get(&id) {
+                Some(entry) if entry.raw == raw => Entry {
+                    raw,
+                    value: entry.value.clone(),
+                },
+                known => {
+                    let Ok(value) = parse(&id, &raw) else {
+                        continue;
+                    };
+                    changes.push(ResourceChange {
+                        key: ResourceKey {
+                            kind,
+                            id: id.clone(),
+                        },
+                        kind: match known {
+                            Some(_) => ChangeKind::Updated,
+                            None => ChangeKind::Added,
+                        },
+                    });
+                    Entry {
+                        value: Arc::new(value),
+                        raw,
+                    }
+                }
+            };
+            entries.insert(id, entry);
+        }
+        changes.extend(
+            self.entries
+                .keys()
+                .filter(|id| !entries.contains_key(*id))
+                .map(|id| ResourceChange {
+                    key: ResourceKey {
+                        kind,
+                        id: id.clone(),
+                    },
+                    kind: ChangeKind::Removed,
+                }),
+        );
+        self.entries = entries;
+        changes
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Store {
+    pub lights: Collection<HueLight>,
+    pub groups: Collection<HueGroup>,
+    pub scenes: Collection<HueScene>,
+    pub sensors: Collection<HueSensor>,
+}
+
+/// The bridge only sends the `lightstates` of a scene if the scene is requested on it's own.
+/// They are removed so a reloaded scene can be compared with the scenes of the list
+fn without_light_states(mut raw: serde_json::Value) -> serde_json::Value {
+    if let Some(scene) = raw.as_object_mut() {
+        scene.remove("lightstates");
+    }
+    raw
+}
+
+impl Store {
+    pub fn set(
+        &mut self,
+        key: &ResourceKey,
+        raw: serde_json::Value,
+    ) -> Result<Option<ChangeKind>, HueError> {
+        match key.kind {
+            ResourceKind::Light => self.lights.set(&key.id, raw),
+            ResourceKind::Group => self.groups.set(&key.id, raw),
+            ResourceKind::Scene => self.scenes.set(&key.id, without_light_states(raw)),
+            ResourceKind::Sensor => self.sensors.set(&key.id, raw),
+        }
+    }
+
+    pub fn remove(&mut self, key: &ResourceKey) -> Option<ChangeKind> {
+        match key.kind {
+            ResourceKind::Light => self.lights.remove(&key.id),
+            ResourceKind::Group => self.groups.remove(&key.id),
+            ResourceKind::Scene => self.scenes.remove(&key.id),
+            ResourceKind::Sensor => self.sensors.remove(&key.id),
+        }
+    }
+
+    pub fn replace_all(
+        &mut self,
+        kind: ResourceKind,
+        resources: HashMap<String, serde_json::Value>,
+    ) -> Vec<ResourceChange> {
+        match kind {
+            ResourceKind::Light => self.lights.replace_all(resources, kind),
+            ResourceKind::Group => self.groups.replace_all(resources, kind),
+            ResourceKind::Scene => self.scenes.replace_all(resources, kind),
+            ResourceKind::Sensor => self.sensors.replace_all(resources, kind),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Named {
+        name: String,
+    }
+
+    fn resources(resources: &[(&str, serde_json::Value)]) -> HashMap<String, serde_json::Value> {
+        resources
+            .iter()
+            .map(|(id, raw)| (id.to_string(), raw.clone()))
+            .collect()
+    }
+
+    fn change(id: &str, kind: ChangeKind) -> ResourceChange {
+        ResourceChange {
+            key: ResourceKey {
+                kind: ResourceKind::Light,
+                id: id.to_string(),
+            },
+            kind,
+        }
+    }
+
+    #[test]
+    fn set_and_remove() {
+        let mut collection = Collection::<Named>::default();
+        let set = |collection: &mut Collection<Named>, name: &str| {
+            collection.set("1", json!({ "name": name })).unwrap()
+        };
+        assert_eq!(set(&mut collection, "a"), Some(ChangeKind::Added));
+        assert_eq!(set(&mut collection, "a"), None);
+        assert_eq!(set(&mut collection, "b"), Some(ChangeKind::Updated));
+        assert_eq!(collection.get("1").unwrap().name, "b");
+        assert_eq!(collection.remove("1"), Some(ChangeKind::Removed));
+        assert_eq!(collection.remove("1"), None);
+        assert!(collection.get("1").is_none());
+    }
+
+    #[test]
+    fn invalid_resource_is_not_set() {
+        let mut collection = Collection::<Named>::default();
+        collection.set("1", json!({ "name": "a" })).unwrap();
+        assert!(matches!(
+            collection.set("1", json!({ "name": 1 })),
+            Err(HueError::InvalidResource(_))
+        ));
+        assert_eq!(collection.get("1").unwrap().name, "a");
+    }
+
+    #[test]
+    fn replace_all_diffs_the_resources() {
+        let mut collection = Collection::<Named>::default();
+        collection.replace_all(
+            resources(&[
+                ("1", json!({ "name": "a" })),
+                ("2", json!({ "name": "b" })),
+                ("3", json!({ "name": "c" })),
+            ]),
+            ResourceKind::Light,
+        );
+        let unchanged = collection.get("1").unwrap();
+
+        let mut changes = collection.replace_all(
+            resources(&[
+                ("1", json!({ "name": "a" })),
+                ("2", json!({ "name": "B" })),
+                ("4", json!({ "name": "d" })),
+            ]),
+            ResourceKind::Light,
+        );
+        changes.sort_by(|a, b| a.key.id.cmp(&b.key.id));
+        assert_eq!(
+            changes,
+            vec![
+                change("2", ChangeKind::Updated),
+                change("3", ChangeKind::Removed),
+                change("4", ChangeKind::Added),
+            ]
+        );
+        assert!(Arc::ptr_eq(&unchanged, &collection.get("1").unwrap()));
+        assert_eq!(collection.get("2").unwrap().name, "B");
+        assert!(collection.get("3").is_none());
+    }
+
+    #[test]
+    fn replace_all_skips_invalid_resources() {
+        let mut collection = Collection::<Named>::default();
+        collection.replace_all(
+            resources(&[("1", json!({ "name": "a" })), ("2", json!({ "name": "b" }))]),
+            ResourceKind::Light,
+        );
+        let mut changes = collection.replace_all(
+            resources(&[
+                ("1", json!({ "name": "A" })),
+                ("2", json!({})),
+                ("3", json!({ "name": 3 })),
+            ]),
+            ResourceKind::Light,
+        );
+        changes.sort_by(|a, b| a.key.id.cmp(&b.key.id));
+        // the resource that became invalid is removed, it's old value would be outdated
+        assert_eq!(
+            changes,
+            vec![
+                change("1", ChangeKind::Updated),
+                change("2", ChangeKind::Removed),
+            ]
+        );
+        assert_eq!(collection.all().len(), 1);
+        assert_eq!(collection.get("1").unwrap().name, "A");
+    }
+
+    #[test]
+    fn reloaded_scene_equals_the_listed_scene() {
+        let scene = json!({
+            "name": "Relax",
+            "type": "GroupScene",
+            "group": "1",
+            "lights": ["1", "2"],
+            "owner": "username",
+            "recycle": false,
+            "locked": false,
+            "picture": "",
+            "lastupdated": "2023-11-14T22:13:20",
+            "version": 2
+        });
+        let mut reloaded = scene.clone();
+        reloaded["lightstates"] = json!({
+            "1": { "on": true, "bri": 144 },
+            "2": { "on": false }
+        });
+        let key = ResourceKey {
+            kind: ResourceKind::Scene,
+            id: "abc".to_string(),
+        };
+        let mut store = Store::default();
+        store.replace_all(ResourceKind::Scene, resources(&[("abc", scene)]));
+        assert_eq!(store.set(&key, reloaded).unwrap(), None);
+        assert!(store.scenes.get("abc").unwrap().light_states.is_none());
+    }
+}
@@ -0,0 +1,167 @@
+use std::{collections::HashMap, time::Duration};
+
+use futures_util::StreamExt;
+
+use crate::{
+    bridge::DataResponse,
+    error::{ApiErrorKind, HueError},
+    v2::{EventType, ResourceType},
+};
+
+use super::{BridgeState, ResourceChange, ResourceKey, ResourceKind};
+
+/// These resources share the v1 id of a light or sensor but the v1 resource doesn't change with them
+const IGNORED_RESOURCE_TYPES: [ResourceType; 2] =
+    [ResourceType::Device, ResourceType::ZigbeeConnectivity];
+
+/// The v1 api replies with a list of `error` objects instead of the resource if the request failed
+fn into_resource(value: serde_json::Value) -> Result<serde_json::Value, HueError> {
+    if !value.is_array() {
+        return Ok(value);
+    }
+    serde_json::from_value::<Vec<DataResponse<serde_json::Value>>>(value)
+        .map_err(|e| HueError::InvalidResource(e.to_string()))?
+        .into_iter()
+        .next()
+        .map_or(Err(HueError::NoData), Into::into)
+}
+
+impl BridgeState {
+    async fn fetch(&self, path: &str) -> Result<serde_json::Value, HueError> {
+        let hue = &self.inner.hue;
+        into_resource(
+            hue.bridge
+                .client()
+                .get(format!("{}/{path}", hue.get_username_url()))
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?,
+        )
+    }
+
+    async fn fetch_all(
+        &self,
+        kind: ResourceKind,
+    ) -> Result<HashMap<String, serde_json::Value>, HueError> {
+        serde_json::from_value(self.fetch(kind.path()).await?)
+            .map_err(|e| HueError::InvalidResource(e.to_string()))
+    }
+
+    /// Get all resources from the bridge and replace the state.
+    /// The state is only changed after all resources were fetched, resources that can't be parsed are left out of the state
+    pub async fn refresh(&self) -> Result<(), HueError> {
+        let (lights, groups, scenes, sensors) = futures_util::try_join!(
+            self.fetch_all(ResourceKind::Light),
+            self.fetch_all(ResourceKind::Group),
+            self.fetch_all(ResourceKind::Scene),
+            self.fetch_all(ResourceKind::Sensor),
+        )?;
+        let changes = {
+            let mut store = self.inner.store.write().unwrap();
+            let mut changes = store.replace_all(ResourceKind::Light, lights);
+            changes.extend(store.replace_all(ResourceKind::Group, groups));
+            changes.extend(store.replace_all(ResourceKind::Scene, scenes));
+            changes.extend(store.replace_all(ResourceKind::Sensor, sensors));
+            changes
+        };
+        self.publish(changes);
+        Ok(())
+    }
+
+    /// Get one resource from the bridge, it's removed if the bridge doesn't know it anymore.
+    /// Like in `refresh` a resource that can't be parsed is removed too
+    async fn reload(&self, key: &ResourceKey) -> Result<(), HueError> {
+        let change = match self.fetch(&format!("{}/{}", key.kind.path(), key.id)).await {
+            Ok(raw) => {
+                let mut store = self.inner.store.write().unwrap();
+                store.set(key, raw).unwrap_or_else(|_| store.remove(key))
+            }
+            Err(e) if e.is_api_error(ApiErrorKind::ResourceNotAvailable) => {
+                self.inner.store.write().unwrap().remove(key)
+            }
+            Err(e) => return Err(e),
+        };
+        self.publish(
+            change
+                .map(|kind| ResourceChange {
+                    key: key.clone(),
+                    kind,
+                })
+                .into_iter()
+                .collect(),
+        );
+        Ok(())
+    }
+
+    fn remove(&self, key: &ResourceKey) {
+        let change = self.inner.store.write().unwrap().remove(key);
+        self.publish(
+            change
+                .map(|kind| ResourceChange {
+                    key: key.clone(),
+                    kind,
+                })
+                .into_iter()
+                .collect(),
+        );
+    }
+
+    /// Keep the state in sync with the event stream of the v2 api, this function runs until the event stream ends.
+    /// Every changed resource is fetched from the v1 api, the whole state is refreshed after the connection to the event stream was lost.
+    /// The error that ended the event stream (like an unauthorized username) is returned
+    pub async fn sync_events(&self) -> Result<(), HueError> {
+        let mut events = self.inner.hue.v2().event_stream().stream();
+        let mut needs_refresh = false;
+        let mut last_error = None;
+        while let Some(event) = events.next().await {
+            let event = match event {
+                Ok(event) => event,
+                Err(e) => {
+                    // events may have been missed while the connection was lost
+                    needs_refresh = self.refresh().await.is_err();
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+            if needs_refresh {
+                needs_refresh = self.refresh().await.is_err();
+                continue;
+            }
+            if IGNORED_RESOURCE_TYPES.contains(&event.resource.resource_type) {
+                continue;
+            }
+            // only resources of the v1 api are part of the state
+            let Some(key) = event
+                .resource
+                .id_v1
+                .as_deref()
+                .and_then(ResourceKey::from_path)
+            else {
+                continue;
+            };
+            match event.event_type {
+                EventType::Delete => self.remove(&key),
+                _ => needs_refresh = self.reload(&key).await.is_err(),
+            }
+        }
+        match last_error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Keep the state in sync by refreshing it every `interval`, this works with the v1 api only.
+    /// Failed refreshes are retried after the next `interval`, the function only returns if the username is unauthorized
+    pub async fn sync_polling(&self, interval: Duration) -> Result<(), HueError> {
+        loop {
+            tokio::time::sleep(interval).await;
+            if let Err(e) = self.refresh().await {
+                if e.is_unauthorized() {
+                    return Err(e);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use std::{collections::VecDeque, time::Duration};
+
+use futures_util::{stream::BoxStream, StreamExt};
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::{error::HueError, v2::HueV2};
+
+use super::{ResourceIdentifier, ResourceType};
+
+const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// The kind of change of an event
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventType {
+    Add,
+    Update,
+    Delete,
+    Error,
+    #[serde(other)]
+    Other,
+}
+
+/// The change of one resource that was sent by the event stream
+#[derive(Clone, Debug)]
+pub struct HueEvent {
+    /// The id of the server-sent event that contained this event, pass it to `EventStream::last_event_id` to resume the stream
+    pub last_event_id: Option<String>,
+    /// The UUID of the event
+    pub id: String,
+    pub creation_time: String,
+    pub event_type: EventType,
+    pub resource: EventResource,
+}
+
+/// The resource of an event, `update` events only contain the attributes that changed
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventResource {
+    /// The UUID of the resource
+    pub id: String,
+    #[serde(rename = "type")]
+    pub resource_type: ResourceType,
+    pub id_v1: Option<String>,
+    pub owner: Option<ResourceIdentifier>,
+    /// All other attributes of the resource
+    #[serde(flatten)]
+    pub attributes: serde_json::Map<String, serde_json::Value>,
+}
+
+impl EventResource {
+    /// Parse the resource like `v2::Light`, this only works for `add` events because the other events don't contain every attribute
+    pub fn parse<T>(&self) -> Result<T, serde_json::Error>
+    where
+        T: DeserializeOwned,
+    {
+        let mut value = self.attributes.clone();
+        value.insert("id".to_string(), self.id.clone().into());
+        value.insert("type".to_string(), self.resource_type.as_str().into());
+        if let Some(id_v1) = &self.id_v1 {
+            value.insert("id_v1".to_string(), id_v1.clone().into());
+        }
+        if let Some(owner) = &self.owner {
+            value.insert("owner".to_string(), serde_json::to_value(owner)?);
+        }
+        serde_json::from_value(value.into())
+    }
+}
+
+/// Every server-sent event contains a list of these events
+#[derive(Deserialize)]
+struct RawEvent {
+    id: String,
+    #[serde(rename = "creationtime")]
+    creation_time: String,
+    #[serde(rename = "type")]
+    event_type: EventType,
+    data: Vec<EventResource>,
+}
+
+/// Listen to the changes of the bridge with `/eventstream/clip/v2` instead of polling the resources
+/// ```
+/// use futures_util::StreamExt;
+/// use philipshue::{HueBridge, v2::{HueV2, ResourceType}};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let hue = HueV2::new(HueBridge::new("url".to_string()), "username".to_string());
+///     let mut events = hue
+///         .event_stream()
+///         .resource_type(ResourceType::Light) // only listen to lights
+///         .stream();
+///     while let Some(event) = events.next().await {
+///         let event = event.unwrap();
+///         println!("{:?} {}", event.event_type, event.resource.id);
+///     }
+/// }
+/// ```
+/// The stream reconnects automatically and resumes with the `Last-Event-ID` header. Every failed connection is yielded as an error,
+/// only if the bridge rejects the username (`401` or `403`) or doesn't support the event stream (`404`) the stream ends.
+/// A `timeout` of the `HueBridgeBuilder` also ends the connection, the stream reconnects after it.
+#[derive(Clone)]
+pub struct EventStream {
+    hue: HueV2,
+    last_event_id: Option<String>,
+    reconnect_delay: Duration,
+    resource_types: Vec<ResourceType>,
+}
+
+impl HueV2 {
+    /// Create an `EventStream` to listen to the changes of the bridge
+    pub fn event_stream(&self) -> EventStream {
+        EventStream {
+            hue: self.clone(),
+            last_event_id: None,
+            reconnect_delay: DEFAULT_RECONNECT_DELAY,
+            resource_types: Vec::new(),
+        }
+    }
+}
+
+impl EventStream {
+    /// Only yield events of this resource type, call it multiple times to listen to multiple types. All events are yielded by default
+    pub fn resource_type(mut self, resource_type: ResourceType) -> Self {
+        self.resource_types.push(resource_type);
+        self
+    }
+
+    /// Resume the stream after the event with this id (`HueEvent::last_event_id`)
+    pub fn last_event_id(mut self, last_event_id: String) -> Self {
+        self.last_event_id = Some(last_event_id);
+        self
+    }
+
+    /// The time to wait before reconnecting, `1s` by default. The bridge can change it with the `retry` field
+    pub fn reconnect_delay(mut self, reconnect_delay: Duration) -> Self {
+        self.reconnect_delay = reconnect_delay;
+        self
+    }
+
+    /// Connect to the bridge and yield the events
+    pub fn stream(self) -> BoxStream<'static, Result<HueEvent, HueError>> {
+        let state = StreamState {
+            config: self,
+            body: None,
+            buffer: Vec::new(),
+            message: Message::default(),
+            events: VecDeque::new(),
+            connected_before: false,
+            finished: false,
+        };
+        futures_util::stream::unfold(state, |mut state| async move {
+            let event = state.next().await?;
+            Some((event, state))
+        })
+        .boxed()
+    }
+}
+
+/// The fields of the server-sent event that is currently received
+#[derive(Default)]
+struct Message {
+    id: Option<String>,
+    data: String,
+}
+
+struct StreamState {
+    config: EventStream,
+    body: Option<BoxStream<'static, reqwest::Result<Vec<u8>>>>,
+    /// The received bytes of the current line
+    buffer: Vec<u8>,
+    message: Message,
+    events: VecDeque<Result<HueEvent, HueError>>,
+    connected_before: bool,
+    finished: bool,
+}
+
+impl StreamState {
+    async fn next(&mut self) -> Option<Result<HueEvent, HueError>> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Some(event);
+            }
+            if self.finished {
+                return None;
+            }
+            match &mut self.body {
+                None => {
+                    if let Err(e) = self.connect().await {
+                        return Some(Err(e));
+                    }
+                }
+                Some(body) => match body.next().await {
+                    Some(Ok(bytes)) => self.receive(&bytes),
+                    Some(Err(e)) => {
+                        self.disconnect();
+                        return Some(Err(e.into()));
+                    }
+                    None => self.disconnect(),
+                },
+            }
+        }
+    }
+
+    async fn connect(&mut self) -> Result<(), HueError> {
+        if self.connected_before {
+            tokio::time::sleep(self.config.reconnect_delay).await;
+        }
+        self.connected_before = true;
+        let hue = &self.config.hue;
+        let mut request = hue
+            .bridge
+            .client()
+            .get(format!("{}/eventstream/clip/v2", hue.bridge.bridge_url))
+            .header("hue-application-key", &hue.username)
+            .header("Accept", "text/event-stream");
+        if let Some(last_event_id) = &self.config.last_event_id {
+            request = request.header("Last-Event-ID", last_event_id);
+        }
+        let response = request.send().await?;
+        if let Err(e) = response.error_for_status_ref() {
+            // the bridge won't accept the request if it's retried, other errors like `503` are temporary
+            if matches!(
+                response.status(),
+                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN | StatusCode::NOT_FOUND
+            ) {
+                self.finished = true;
+            }
+            return Err(e.into());
+        }
+        self.body = Some(
+            response
+                .bytes_stream()
+                .map(|bytes| bytes.map(|bytes| bytes.to_vec()))
+                .boxed(),
+        );
+        Ok(())
+    }
+
+    /// The message that was received partially is dropped, the bridge sends it again because of the `Last-Event-ID`
+    fn disconnect(&mut self) {
+        self.body = None;
+        self.buffer.clear();
+        self.message = Message::default();
+    }
+
+    fn receive(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+        while let Some(position) = self.buffer.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=position).collect();
+            let line = String::from_utf8_lossy(&line);
+            self.receive_line(line.trim_end_matches(['\n', '\r']));
+        }
+    }
+
+    fn receive_line(&mut self, line: &str) {
+        if line.is_empty() {
+            let message = std::mem::take(&mut self.message);
+            self.dispatch(message);
+            return;
+        }
+        // lines starting with a colon are comments
+        if line.starts_with(':') {
+            return;
+        }
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "id" => self.message.id = Some(value.to_string()),
+            "data" => {
+                if !self.message.data.is_empty() {
+                    self.message.data.push('\n');
+                }
+                self.message.data.push_str(value);
+            }
+            "retry" => {
+                if let Ok(retry) = value.parse() {
+                    self.config.reconnect_delay = Duration::from_millis(retry);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn dispatch(&mut self, message: Message) {
+        if message.id.is_some() {
+            self.config.last_event_id = message.id;
+        }
+        if message.data.is_empty() {
+            return;
+        }
+        let raw_events = match serde_json::from_str::<Vec<RawEvent>>(&message.data) {
+            Ok(raw_events) => raw_events,
+            Err(e) => {
+                self.events
+                    .push_back(Err(HueError::InvalidEvent(e.to_string())));
+                return;
+            }
+        };
+        for raw_event in raw_events {
+            for resource in raw_event.data {
+                if !self.config.resource_types.is_empty()
+                    && !self.config.resource_types.contains(&resource.resource_type)
+                {
+                    continue;
+                }
+                self.events.push_back(Ok(HueEvent {
+                    last_event_id: self.config.last_event_id.clone(),
+                    id: raw_event.id.clone(),
+                    creation_time: raw_event.creation_time.clone(),
+                    event_type: raw_event.event_type,
+                    resource,
+                }));
+            }
+        }
+    }
+}
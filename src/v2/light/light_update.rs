@@ -0,0 +1,289 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::{GradientColor, GradientPoint, LightEffect, On, TimedEffect, Xy};
+
+/// The direction of a relative change like `LightUpdate::brightness_delta`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeltaAction {
+    Up,
+    Down,
+    /// Stop a running change
+    Stop,
+}
+
+#[derive(Clone, Copy, Serialize)]
+struct Brightness {
+    brightness: f32,
+}
+
+#[derive(Clone, Copy, Serialize)]
+struct BrightnessDelta {
+    action: DeltaAction,
+    brightness_delta: f32,
+}
+
+#[derive(Clone, Copy, Serialize)]
+struct Mirek {
+    mirek: u16,
+}
+
+#[derive(Clone, Copy, Serialize)]
+struct MirekDelta {
+    action: DeltaAction,
+    mirek_delta: u16,
+}
+
+#[derive(Clone, Copy, Serialize)]
+struct ColorXy {
+    xy: Xy,
+}
+
+#[derive(Clone, Copy, Default, Serialize)]
+struct DynamicsUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speed: Option<f32>,
+}
+
+#[derive(Clone, Copy, Serialize)]
+struct AlertAction {
+    action: &'static str,
+}
+
+#[derive(Clone, Serialize)]
+struct EffectUpdate {
+    effect: LightEffect,
+}
+
+#[derive(Clone, Serialize)]
+struct TimedEffectUpdate {
+    effect: TimedEffect,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<u32>,
+}
+
+#[derive(Clone, Serialize)]
+struct GradientUpdate {
+    points: Vec<GradientPoint>,
+}
+
+/// Build `LightUpdate` objects to change a `v2::Light`
+/// ```
+/// use std::time::Duration;
+/// use philipshue::v2::LightUpdate;
+///
+/// let update = LightUpdate::new()
+///     .on(true) // turn the light on
+///     .brightness(100.0) // set the brightness to 100% (maximum)
+///     .xy(0.675, 0.322) // set the color to red
+///     .transition(Duration::from_secs(1)); // change the light within one second
+/// ```
+/// The bridge doesn't accept a value and it's delta (like `brightness` and `brightness_delta`) at the same time, so setting one of them removes the other one.
+#[derive(Default, Clone, Serialize)]
+pub struct LightUpdate {
+    #[serde(rename = "on", skip_serializing_if = "Option::is_none")]
+    value_on: Option<On>,
+    #[serde(rename = "dimming", skip_serializing_if = "Option::is_none")]
+    value_dimming: Option<Brightness>,
+    #[serde(rename = "dimming_delta", skip_serializing_if = "Option::is_none")]
+    value_dimming_delta: Option<BrightnessDelta>,
+    #[serde(rename = "color_temperature", skip_serializing_if = "Option::is_none")]
+    value_color_temperature: Option<Mirek>,
+    #[serde(
+        rename = "color_temperature_delta",
+        skip_serializing_if = "Option::is_none"
+    )]
+    value_color_temperature_delta: Option<MirekDelta>,
+    #[serde(rename = "color", skip_serializing_if = "Option::is_none")]
+    value_color: Option<ColorXy>,
+    #[serde(rename = "dynamics", skip_serializing_if = "Option::is_none")]
+    value_dynamics: Option<DynamicsUpdate>,
+    #[serde(rename = "alert", skip_serializing_if = "Option::is_none")]
+    value_alert: Option<AlertAction>,
+    #[serde(rename = "effects", skip_serializing_if = "Option::is_none")]
+    value_effects: Option<EffectUpdate>,
+    #[serde(rename = "timed_effects", skip_serializing_if = "Option::is_none")]
+    value_timed_effects: Option<TimedEffectUpdate>,
+    #[serde(rename = "gradient", skip_serializing_if = "Option::is_none")]
+    value_gradient: Option<GradientUpdate>,
+}
+
+impl LightUpdate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no value was set (every value is `None`)
+    pub fn is_empty(&self) -> bool {
+        self.value_on.is_none()
+            && self.value_dimming.is_none()
+            && self.value_dimming_delta.is_none()
+            && self.value_color_temperature.is_none()
+            && self.value_color_temperature_delta.is_none()
+            && self.value_color.is_none()
+            && self.value_dynamics.is_none()
+            && self.value_alert.is_none()
+            && self.value_effects.is_none()
+            && self.value_timed_effects.is_none()
+            && self.value_gradient.is_none()
+    }
+
+    pub fn on(mut self, on: bool) -> Self {
+        self.value_on = Some(On { on });
+        self
+    }
+
+    /// The brightness in percent (between `0` and `100`), removes `brightness_delta`
+    pub fn brightness(mut self, brightness: f32) -> Self {
+        self.value_dimming = Some(Brightness { brightness });
+        self.value_dimming_delta = None;
+        self
+    }
+
+    /// Increase or decrease the brightness by `brightness_delta` percent, removes `brightness`
+    pub fn brightness_delta(mut self, action: DeltaAction, brightness_delta: f32) -> Self {
+        self.value_dimming_delta = Some(BrightnessDelta {
+            action,
+            brightness_delta,
+        });
+        self.value_dimming = None;
+        self
+    }
+
+    /// The color temperature in mirek, removes `mirek_delta`
+    pub fn mirek(mut self, mirek: u16) -> Self {
+        self.value_color_temperature = Some(Mirek { mirek });
+        self.value_color_temperature_delta = None;
+        self
+    }
+
+    /// Increase or decrease the color temperature by `mirek_delta` mirek, removes `mirek`
+    pub fn mirek_delta(mut self, action: DeltaAction, mirek_delta: u16) -> Self {
+        self.value_color_temperature_delta = Some(MirekDelta {
+            action,
+            mirek_delta,
+        });
+        self.value_color_temperature = None;
+        self
+    }
+
+    /// The x and y coordinates of a color in the CIE color space (between `0` and `1`)
+    pub fn xy(mut self, x: f32, y: f32) -> Self {
+        self.value_color = Some(ColorXy { xy: Xy { x, y } });
+        self
+    }
+
+    /// The duration of the transition, the bridge uses milliseconds
+    pub fn transition(mut self, transition: Duration) -> Self {
+        self.value_dynamics
+            .get_or_insert_with(Default::default)
+            .duration = Some(transition.as_millis().min(u32::MAX as u128) as u32);
+        self
+    }
+
+    /// The speed of the dynamic palette or effect (between `0` and `1`)
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.value_dynamics
+            .get_or_insert_with(Default::default)
+            .speed = Some(speed);
+        self
+    }
+
+    /// Let the light breathe once
+    pub fn alert(mut self) -> Self {
+        self.value_alert = Some(AlertAction { action: "breathe" });
+        self
+    }
+
+    /// Start an effect, `LightEffect::NoEffect` stops it
+    pub fn effect(mut self, effect: LightEffect) -> Self {
+        self.value_effects = Some(EffectUpdate { effect });
+        self
+    }
+
+    /// Start a timed effect like `TimedEffect::Sunrise` that runs for `duration`
+    pub fn timed_effect(mut self, effect: TimedEffect, duration: Option<Duration>) -> Self {
+        self.value_timed_effects = Some(TimedEffectUpdate {
+            effect,
+            duration: duration.map(|duration| duration.as_millis().min(u32::MAX as u128) as u32),
+        });
+        self
+    }
+
+    /// The colors of the gradient as x and y coordinates, the light supports at most `Gradient::points_capable` points
+    pub fn gradient(mut self, points: Vec<(f32, f32)>) -> Self {
+        self.value_gradient = Some(GradientUpdate {
+            points: points
+                .into_iter()
+                .map(|(x, y)| GradientPoint {
+                    color: GradientColor { xy: Xy { x, y } },
+                })
+                .collect(),
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn effects_are_sent_with_their_names() {
+        let update = LightUpdate::new()
+            .effect(LightEffect::Candle)
+            .timed_effect(TimedEffect::Sunrise, Some(Duration::from_secs(60)));
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            json!({
+                "effects": {"effect": "candle"},
+                "timed_effects": {"effect": "sunrise", "duration": 60000}
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_effects_keep_their_names() {
+        let effect: LightEffect = serde_json::from_value(json!("rainbow")).unwrap();
+        assert_eq!(effect, LightEffect::Other("rainbow".to_string()));
+        let update = LightUpdate::new().effect(effect);
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            json!({"effects": {"effect": "rainbow"}})
+        );
+        let effect: TimedEffect = serde_json::from_value(json!("sleep")).unwrap();
+        assert_eq!(effect, TimedEffect::Other("sleep".to_string()));
+    }
+
+    #[test]
+    fn known_effects_round_trip() {
+        for name in [
+            "prism",
+            "opal",
+            "glisten",
+            "sparkle",
+            "fire",
+            "candle",
+            "underwater",
+            "cosmos",
+            "sunbeam",
+            "enchant",
+            "no_effect",
+        ] {
+            let effect = LightEffect::from(name.to_string());
+            assert!(!matches!(effect, LightEffect::Other(_)), "{name}");
+            assert_eq!(String::from(effect), name);
+        }
+        for name in ["sunrise", "sunset", "no_effect"] {
+            let effect = TimedEffect::from(name.to_string());
+            assert!(!matches!(effect, TimedEffect::Other(_)), "{name}");
+            assert_eq!(String::from(effect), name);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use crate::{error::HueError, v2::HueV2};
+
+use super::{ResourceIdentifier, ResourceType};
+
+mod light_update;
+mod model;
+
+pub use light_update::*;
+pub use model::*;
+
+impl HueV2 {
+    /// Get all lights
+    pub async fn lights(&self) -> Result<Vec<Light>, HueError> {
+        self.list(ResourceType::Light).await
+    }
+
+    /// Get one specific light from it's UUID
+    pub async fn get_light(&self, id: &str) -> Result<Light, HueError> {
+        self.get(ResourceType::Light, id).await
+    }
+
+    /// Change a light
+    /// ```
+    /// use philipshue::{HueBridge, v2::{HueV2, LightUpdate}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let hue = HueV2::new(HueBridge::new("url".to_string()), "username".to_string());
+    ///     hue.update_light(
+    ///         "3f7b8c4e-1a2b-4c5d-9e8f-0a1b2c3d4e5f", // we know the UUID from the lights request for example
+    ///         LightUpdate::new()
+    ///             .on(true) // turn the light on
+    ///             .brightness(100.0) // this is the maximal brightness
+    ///     ).await.unwrap();
+    /// }
+    /// ```
+    pub async fn update_light(
+        &self,
+        id: &str,
+        light_update: LightUpdate,
+    ) -> Result<Vec<ResourceIdentifier>, HueError> {
+        self.put(ResourceType::Light, id, &light_update).await
+    }
+
+    /// Get a `HashMap` from the light numbers of the v1 api (the keys of `Hue::lights`) to the UUIDs of the v2 api
+    pub async fn light_ids(&self) -> Result<HashMap<String, String>, HueError> {
+        Ok(light_ids(self.lights().await?))
+    }
+}
+
+/// Lights without a v1 id (like lights that are only available in the v2 api) are skipped
+fn light_ids(lights: Vec<Light>) -> HashMap<String, String> {
+    lights
+        .into_iter()
+        .filter_map(|light| Some((light.light_number()?.to_string(), light.id)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn light(id: &str, id_v1: Option<&str>) -> Light {
+        serde_json::from_value(json!({
+            "id": id,
+            "id_v1": id_v1,
+            "owner": {"rid": "0a1b2c3d-0000-4000-8000-000000000000", "rtype": "device"},
+            "metadata": {"name": "Hue color lamp", "archetype": "sultan_bulb"},
+            "on": {"on": true},
+            "mode": "normal",
+            "type": "light"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn light_number_from_id_v1() {
+        assert_eq!(light("a", Some("/lights/12")).light_number(), Some("12"));
+        assert_eq!(light("a", Some("/groups/1")).light_number(), None);
+        assert_eq!(light("a", None).light_number(), None);
+    }
+
+    #[test]
+    fn light_ids_map_light_numbers_to_uuids() {
+        let ids = light_ids(vec![
+            light("3f7b8c4e-1a2b-4c5d-9e8f-0a1b2c3d4e5f", Some("/lights/1")),
+            light("9a8b7c6d-1a2b-4c5d-9e8f-0a1b2c3d4e5f", Some("/lights/2")),
+            light("5e4d3c2b-1a2b-4c5d-9e8f-0a1b2c3d4e5f", None),
+        ]);
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids["1"], "3f7b8c4e-1a2b-4c5d-9e8f-0a1b2c3d4e5f");
+        assert_eq!(ids["2"], "9a8b7c6d-1a2b-4c5d-9e8f-0a1b2c3d4e5f");
+    }
+}
@@ -0,0 +1,337 @@
+use serde::{Deserialize, Serialize};
+
+use crate::v2::{ResourceIdentifier, ResourceType};
+
+/// A light of the v2 api, the optional parts are only available if the light supports them
+#[derive(Clone, Debug, Deserialize)]
+pub struct Light {
+    /// The UUID of the light
+    pub id: String,
+    /// The path of the light in the v1 api like `/lights/1`
+    pub id_v1: Option<String>,
+    /// The device the light belongs to
+    pub owner: ResourceIdentifier,
+    pub metadata: LightMetadata,
+    pub on: On,
+    pub dimming: Option<Dimming>,
+    pub color_temperature: Option<ColorTemperature>,
+    pub color: Option<Color>,
+    pub dynamics: Option<Dynamics>,
+    pub alert: Option<LightAlert>,
+    pub effects: Option<Effects>,
+    pub timed_effects: Option<TimedEffects>,
+    pub gradient: Option<Gradient>,
+    pub powerup: Option<Powerup>,
+    pub mode: LightMode,
+    #[serde(rename = "type")]
+    pub _type: ResourceType,
+}
+
+impl Light {
+    /// The light number of the v1 api (the key of `Hue::lights`)
+    pub fn light_number(&self) -> Option<&str> {
+        self.id_v1.as_deref()?.strip_prefix("/lights/")
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct LightMetadata {
+    pub name: String,
+    pub archetype: String,
+    /// The fixed color temperature of lights that can't change it
+    pub fixed_mired: Option<u16>,
+    pub function: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct On {
+    pub on: bool,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct Dimming {
+    /// The brightness in percent
+    pub brightness: f32,
+    /// The minimal brightness in percent the light supports
+    pub min_dim_level: Option<f32>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct ColorTemperature {
+    /// The color temperature in mirek, `None` if the light currently shows a color
+    pub mirek: Option<u16>,
+    pub mirek_valid: bool,
+    pub mirek_schema: MirekSchema,
+}
+
+/// The range of color temperatures the light supports
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct MirekSchema {
+    pub mirek_minimum: u16,
+    pub mirek_maximum: u16,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct Color {
+    pub xy: Xy,
+    pub gamut: Option<Gamut>,
+    pub gamut_type: GamutType,
+}
+
+/// The x and y coordinates of a color in the CIE color space (between `0` and `1`)
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Xy {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// The colors the light can show
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct Gamut {
+    pub red: Xy,
+    pub green: Xy,
+    pub blue: Xy,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum GamutType {
+    A,
+    B,
+    C,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Dynamics {
+    pub status: DynamicsStatus,
+    pub status_values: Vec<DynamicsStatus>,
+    /// The speed of the dynamic palette or effect (between `0` and `1`)
+    pub speed: f32,
+    pub speed_valid: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DynamicsStatus {
+    DynamicPalette,
+    None,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct LightAlert {
+    /// The alerts the light supports like `breathe`
+    pub action_values: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Effects {
+    pub status: LightEffect,
+    pub status_values: Vec<LightEffect>,
+    /// The effects the light supports
+    pub effect_values: Vec<LightEffect>,
+}
+
+/// The effect of a light, effects this crate doesn't know are kept as `LightEffect::Other` with the name the bridge uses
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum LightEffect {
+    Prism,
+    Opal,
+    Glisten,
+    Sparkle,
+    Fire,
+    Candle,
+    Underwater,
+    Cosmos,
+    Sunbeam,
+    Enchant,
+    NoEffect,
+    Other(String),
+}
+
+impl LightEffect {
+    /// The name of the effect the bridge uses like `candle`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Prism => "prism",
+            Self::Opal => "opal",
+            Self::Glisten => "glisten",
+            Self::Sparkle => "sparkle",
+            Self::Fire => "fire",
+            Self::Candle => "candle",
+            Self::Underwater => "underwater",
+            Self::Cosmos => "cosmos",
+            Self::Sunbeam => "sunbeam",
+            Self::Enchant => "enchant",
+            Self::NoEffect => "no_effect",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for LightEffect {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "prism" => Self::Prism,
+            "opal" => Self::Opal,
+            "glisten" => Self::Glisten,
+            "sparkle" => Self::Sparkle,
+            "fire" => Self::Fire,
+            "candle" => Self::Candle,
+            "underwater" => Self::Underwater,
+            "cosmos" => Self::Cosmos,
+            "sunbeam" => Self::Sunbeam,
+            "enchant" => Self::Enchant,
+            "no_effect" => Self::NoEffect,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<LightEffect> for String {
+    fn from(value: LightEffect) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TimedEffects {
+    pub status: TimedEffect,
+    pub status_values: Vec<TimedEffect>,
+    /// The timed effects the light supports
+    pub effect_values: Vec<TimedEffect>,
+    /// The duration of the running timed effect in ms
+    pub duration: Option<u32>,
+}
+
+/// An effect that runs for a duration, effects this crate doesn't know are kept as `TimedEffect::Other` with the name the bridge uses
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum TimedEffect {
+    Sunrise,
+    Sunset,
+    NoEffect,
+    Other(String),
+}
+
+impl TimedEffect {
+    /// The name of the effect the bridge uses like `sunrise`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Sunrise => "sunrise",
+            Self::Sunset => "sunset",
+            Self::NoEffect => "no_effect",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for TimedEffect {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "sunrise" => Self::Sunrise,
+            "sunset" => Self::Sunset,
+            "no_effect" => Self::NoEffect,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<TimedEffect> for String {
+    fn from(value: TimedEffect) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Gradient {
+    pub points: Vec<GradientPoint>,
+    /// The maximal number of points the light supports
+    pub points_capable: u8,
+    pub mode: Option<String>,
+    #[serde(default)]
+    pub mode_values: Vec<String>,
+    /// The number of pixels of the light
+    pub pixel_count: Option<u16>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct GradientPoint {
+    pub color: GradientColor,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct GradientColor {
+    pub xy: Xy,
+}
+
+/// The state of the light after a power loss
+#[derive(Clone, Debug, Deserialize)]
+pub struct Powerup {
+    pub preset: PowerupPreset,
+    pub configured: bool,
+    pub on: PowerupOn,
+    pub dimming: Option<PowerupDimming>,
+    pub color: Option<PowerupColor>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PowerupPreset {
+    Safety,
+    Powerfail,
+    LastOnState,
+    Custom,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PowerupOn {
+    /// `on`, `toggle` or `previous`
+    pub mode: String,
+    pub on: Option<On>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PowerupDimming {
+    /// `dimming` or `previous`
+    pub mode: String,
+    pub dimming: Option<PowerupBrightness>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct PowerupBrightness {
+    pub brightness: f32,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PowerupColor {
+    /// `color_temperature`, `color` or `previous`
+    pub mode: String,
+    pub color_temperature: Option<PowerupMirek>,
+    pub color: Option<PowerupXy>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct PowerupMirek {
+    pub mirek: Option<u16>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct PowerupXy {
+    pub xy: Xy,
+}
+
+/// A light is in the `streaming` mode while it's used by an entertainment configuration
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LightMode {
+    Normal,
+    Streaming,
+    #[serde(other)]
+    Other,
+}
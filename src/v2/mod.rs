@@ -0,0 +1,180 @@
+//! The v2 api (CLIP v2) of the bridge at `/clip/v2/resource`. Newer features of the bridge are only available in this api.
+//! The v2 api needs a bridge with https, the username of the v1 api is used as application key.
+
+use reqwest::RequestBuilder;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{bridge::HueBridge, error::HueError, Hue};
+
+mod event_stream;
+mod light;
+mod resource;
+mod response;
+
+pub use event_stream::*;
+pub use light::*;
+pub use resource::*;
+pub use response::ResponseError;
+
+use response::parse_envelope;
+
+/// An authenticated client for the v2 api
+/// ```
+/// use philipshue::{HueBridge, v2::{HueV2, ResourceType}};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let bridge = HueBridge::new("https://philips-hue.local".to_string());
+///     let hue = HueV2::new(bridge, "username".to_string());
+///     let lights: Vec<serde_json::Value> = hue.list(ResourceType::Light).await.unwrap();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct HueV2 {
+    pub bridge: HueBridge,
+    /// The username is sent as `hue-application-key` header
+    pub username: String,
+}
+
+impl HueV2 {
+    pub fn new(bridge: HueBridge, username: String) -> Self {
+        Self { bridge, username }
+    }
+
+    /// All resource paths are {bridge_url}/clip/v2/resource/{path}
+    pub(crate) fn get_resource_url(&self, path: &str) -> String {
+        format!("{}/clip/v2/resource/{}", self.bridge.bridge_url, path)
+    }
+
+    /// Every request needs the application key
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        request.header("hue-application-key", &self.username)
+    }
+
+    /// Get all resources of all types
+    pub async fn resources<T>(&self) -> Result<Vec<T>, HueError>
+    where
+        T: DeserializeOwned,
+    {
+        parse_envelope(
+            self.authorize(
+                self.bridge
+                    .client()
+                    .get(format!("{}/clip/v2/resource", self.bridge.bridge_url)),
+            )
+            .send()
+            .await?,
+        )
+        .await
+    }
+
+    /// Get all resources of the type `resource_type`
+    pub async fn list<T>(&self, resource_type: ResourceType) -> Result<Vec<T>, HueError>
+    where
+        T: DeserializeOwned,
+    {
+        parse_envelope(
+            self.authorize(
+                self.bridge
+                    .client()
+                    .get(self.get_resource_url(resource_type.as_str())),
+            )
+            .send()
+            .await?,
+        )
+        .await
+    }
+
+    /// Get the resource with the UUID `id`
+    pub async fn get<T>(&self, resource_type: ResourceType, id: &str) -> Result<T, HueError>
+    where
+        T: DeserializeOwned,
+    {
+        parse_envelope(
+            self.authorize(
+                self.bridge
+                    .client()
+                    .get(self.get_resource_url(&format!("{resource_type}/{id}"))),
+            )
+            .send()
+            .await?,
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(HueError::NoData)
+    }
+
+    /// Change the resource with the UUID `id`, the bridge replies with the changed resources
+    pub async fn put<B>(
+        &self,
+        resource_type: ResourceType,
+        id: &str,
+        body: &B,
+    ) -> Result<Vec<ResourceIdentifier>, HueError>
+    where
+        B: Serialize + ?Sized,
+    {
+        parse_envelope(
+            self.authorize(
+                self.bridge
+                    .client()
+                    .put(self.get_resource_url(&format!("{resource_type}/{id}"))),
+            )
+            .json(body)
+            .send()
+            .await?,
+        )
+        .await
+    }
+
+    /// Create a resource and return the identifier of the created resource
+    pub async fn post<B>(
+        &self,
+        resource_type: ResourceType,
+        body: &B,
+    ) -> Result<ResourceIdentifier, HueError>
+    where
+        B: Serialize + ?Sized,
+    {
+        parse_envelope(
+            self.authorize(
+                self.bridge
+                    .client()
+                    .post(self.get_resource_url(resource_type.as_str())),
+            )
+            .json(body)
+            .send()
+            .await?,
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(HueError::NoData)
+    }
+
+    /// Delete the resource with the UUID `id`, the bridge replies with the deleted resources
+    pub async fn delete(
+        &self,
+        resource_type: ResourceType,
+        id: &str,
+    ) -> Result<Vec<ResourceIdentifier>, HueError> {
+        parse_envelope(
+            self.authorize(
+                self.bridge
+                    .client()
+                    .delete(self.get_resource_url(&format!("{resource_type}/{id}"))),
+            )
+            .send()
+            .await?,
+        )
+        .await
+    }
+}
+
+impl Hue {
+    /// Create a client for the v2 api with the same bridge and username
+    pub fn v2(&self) -> HueV2 {
+        HueV2::new(self.bridge.clone(), self.username.clone())
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// The type of a v2 resource, it's used in the path `/clip/v2/resource/{type}`.
+/// Types this crate doesn't know are returned as `ResourceType::Other`
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum ResourceType {
+    Device,
+    Bridge,
+    BridgeHome,
+    Room,
+    Zone,
+    Light,
+    GroupedLight,
+    Scene,
+    SmartScene,
+    Button,
+    RelativeRotary,
+    Motion,
+    CameraMotion,
+    Temperature,
+    LightLevel,
+    Contact,
+    Tamper,
+    DevicePower,
+    DeviceSoftwareUpdate,
+    ZigbeeConnectivity,
+    ZgpConnectivity,
+    ZigbeeDeviceDiscovery,
+    Entertainment,
+    EntertainmentConfiguration,
+    BehaviorScript,
+    BehaviorInstance,
+    GeofenceClient,
+    Geolocation,
+    Homekit,
+    Matter,
+    MatterFabric,
+    Other(String),
+}
+
+impl ResourceType {
+    /// The name of the type the bridge uses like `grouped_light`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Device => "device",
+            Self::Bridge => "bridge",
+            Self::BridgeHome => "bridge_home",
+            Self::Room => "room",
+            Self::Zone => "zone",
+            Self::Light => "light",
+            Self::GroupedLight => "grouped_light",
+            Self::Scene => "scene",
+            Self::SmartScene => "smart_scene",
+            Self::Button => "button",
+            Self::RelativeRotary => "relative_rotary",
+            Self::Motion => "motion",
+            Self::CameraMotion => "camera_motion",
+            Self::Temperature => "temperature",
+            Self::LightLevel => "light_level",
+            Self::Contact => "contact",
+            Self::Tamper => "tamper",
+            Self::DevicePower => "device_power",
+            Self::DeviceSoftwareUpdate => "device_software_update",
+            Self::ZigbeeConnectivity => "zigbee_connectivity",
+            Self::ZgpConnectivity => "zgp_connectivity",
+            Self::ZigbeeDeviceDiscovery => "zigbee_device_discovery",
+            Self::Entertainment => "entertainment",
+            Self::EntertainmentConfiguration => "entertainment_configuration",
+            Self::BehaviorScript => "behavior_script",
+            Self::BehaviorInstance => "behavior_instance",
+            Self::GeofenceClient => "geofence_client",
+            Self::Geolocation => "geolocation",
+            Self::Homekit => "homekit",
+            Self::Matter => "matter",
+            Self::MatterFabric => "matter_fabric",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "device" => Self::Device,
+            "bridge" => Self::Bridge,
+            "bridge_home" => Self::BridgeHome,
+            "room" => Self::Room,
+            "zone" => Self::Zone,
+            "light" => Self::Light,
+            "grouped_light" => Self::GroupedLight,
+            "scene" => Self::Scene,
+            "smart_scene" => Self::SmartScene,
+            "button" => Self::Button,
+            "relative_rotary" => Self::RelativeRotary,
+            "motion" => Self::Motion,
+            "camera_motion" => Self::CameraMotion,
+            "temperature" => Self::Temperature,
+            "light_level" => Self::LightLevel,
+            "contact" => Self::Contact,
+            "tamper" => Self::Tamper,
+            "device_power" => Self::DevicePower,
+            "device_software_update" => Self::DeviceSoftwareUpdate,
+            "zigbee_connectivity" => Self::ZigbeeConnectivity,
+            "zgp_connectivity" => Self::ZgpConnectivity,
+            "zigbee_device_discovery" => Self::ZigbeeDeviceDiscovery,
+            "entertainment" => Self::Entertainment,
+            "entertainment_configuration" => Self::EntertainmentConfiguration,
+            "behavior_script" => Self::BehaviorScript,
+            "behavior_instance" => Self::BehaviorInstance,
+            "geofence_client" => Self::GeofenceClient,
+            "geolocation" => Self::Geolocation,
+            "homekit" => Self::Homekit,
+            "matter" => Self::Matter,
+            "matter_fabric" => Self::MatterFabric,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(value: ResourceType) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+impl Display for ResourceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A reference to a v2 resource, the bridge replies with it to changes and uses it to link resources (for example the `owner` of a light)
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ResourceIdentifier {
+    /// The UUID of the resource
+    pub rid: String,
+    pub rtype: ResourceType,
+}
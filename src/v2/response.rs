@@ -0,0 +1,148 @@
+use std::fmt::Display;
+
+use reqwest::Response;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::error::HueError;
+
+/// Every v2 response is wrapped in an envelope with the `errors` and the `data` of the request
+#[derive(Deserialize)]
+struct ResponseEnvelope<T> {
+    #[serde(default)]
+    errors: Vec<ResponseError>,
+    // `default` would require `T: Default`
+    #[serde(default = "Vec::new")]
+    data: Vec<T>,
+}
+
+/// An error of the v2 api, unlike the v1 `ApiError` it only contains a description
+#[derive(Clone, Debug, Deserialize)]
+pub struct ResponseError {
+    pub description: String,
+}
+
+impl Display for ResponseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.description)
+    }
+}
+
+/// Parse the envelope of the response, the request failed if it contains any error.
+/// The bridge also replies with an envelope if the http status isn't successful (for example `404` or `403`).
+/// If only a part of the request succeeded the bridge replies with `207 Multi-Status`, the `data` of the
+/// succeeded part is dropped and only the `errors` are returned as `HueError::V2ApiError`
+pub(crate) async fn parse_envelope<T>(response: Response) -> Result<Vec<T>, HueError>
+where
+    T: DeserializeOwned,
+{
+    match response.error_for_status_ref() {
+        Ok(_) => {
+            let envelope = response.json::<ResponseEnvelope<T>>().await?;
+            if envelope.errors.is_empty() {
+                Ok(envelope.data)
+            } else {
+                Err(HueError::V2ApiError(envelope.errors))
+            }
+        }
+        Err(status_error) => match response.json::<ResponseEnvelope<T>>().await {
+            Ok(envelope) if !envelope.errors.is_empty() => {
+                Err(HueError::V2ApiError(envelope.errors))
+            }
+            _ => Err(status_error.into()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct ResourceIdentifier {
+        rid: String,
+    }
+
+    async fn parse(status: u16, body: &str) -> Result<Vec<ResourceIdentifier>, HueError> {
+        let response = http::Response::builder()
+            .status(status)
+            .header("Content-Type", "application/json")
+            .body(body.to_string())
+            .unwrap();
+        parse_envelope(Response::from(response)).await
+    }
+
+    fn descriptions(error: HueError) -> Vec<String> {
+        match error {
+            HueError::V2ApiError(errors) => errors.into_iter().map(|e| e.description).collect(),
+            other => panic!("expected a v2 api error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn data_without_errors() {
+        let data = parse(
+            200,
+            r#"{"errors": [], "data": [{"rid": "a"}, {"rid": "b"}]}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            data,
+            vec![
+                ResourceIdentifier {
+                    rid: "a".to_string()
+                },
+                ResourceIdentifier {
+                    rid: "b".to_string()
+                }
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn data_with_errors() {
+        let error = parse(
+            200,
+            r#"{"errors": [{"description": "device (light) is \"soft off\", command (.on) may not have effect"}], "data": [{"rid": "a"}]}"#,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            descriptions(error),
+            vec![r#"device (light) is "soft off", command (.on) may not have effect"#]
+        );
+    }
+
+    #[tokio::test]
+    async fn unsuccessful_status_with_errors() {
+        let error = parse(
+            404,
+            r#"{"errors": [{"description": "Not Found"}], "data": []}"#,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(descriptions(error), vec!["Not Found"]);
+    }
+
+    #[tokio::test]
+    async fn unsuccessful_status_without_envelope() {
+        let error = parse(503, "Service Unavailable").await.unwrap_err();
+        assert!(
+            matches!(error, HueError::RequestError(e) if e.status() == Some(reqwest::StatusCode::SERVICE_UNAVAILABLE))
+        );
+    }
+
+    #[tokio::test]
+    async fn multi_status_drops_the_data() {
+        let error = parse(
+            207,
+            r#"{"errors": [{"description": "invalid value for color temperature"}], "data": [{"rid": "a"}]}"#,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            descriptions(error),
+            vec!["invalid value for color temperature"]
+        );
+    }
+}
@@ -0,0 +1,175 @@
+#![cfg(feature = "entertainment")]
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use philipshue::{
+    entertainment::{ChannelColor, EntertainmentOptions, Frame},
+    Hue, HueBridge,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    sync::mpsc,
+};
+use webrtc_dtls::{
+    cipher_suite::CipherSuiteId, config::Config, listener::listen, Error as DtlsError,
+};
+use webrtc_util::conn::Listener;
+
+const CONFIGURATION_ID: &str = "1e3f4c2a-5b6d-4e7f-8a9b-0c1d2e3f4a5b";
+const CLIENT_KEY: &str = "0123456789ABCDEF0123456789ABCDEF";
+
+/// Start a bridge that accepts every request to the v2 api and reports the request line and body
+async fn bridge() -> (String, mpsc::UnboundedReceiver<(String, String)>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (requests, received) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            let (head, body) = loop {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                    let content_length = head
+                        .lines()
+                        .find_map(|line| {
+                            line.to_lowercase()
+                                .strip_prefix("content-length: ")
+                                .map(str::to_string)
+                        })
+                        .map_or(0, |length| length.parse().unwrap());
+                    if body.len() >= content_length {
+                        break (head.to_string(), body.to_string());
+                    }
+                }
+            };
+            let request_line = head.lines().next().unwrap().to_string();
+            let _ = requests.send((request_line, body));
+            let response = format!(
+                r#"{{"data": [{{"rid": "{CONFIGURATION_ID}", "rtype": "entertainment_configuration"}}], "errors": []}}"#
+            );
+            socket
+                .write_all(
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                        response.len()
+                    )
+                    .as_bytes(),
+                )
+                .await
+                .unwrap();
+        }
+    });
+    (url, received)
+}
+
+/// Start a DTLS server with the PSK of the client key, the identity the client used is reported
+async fn dtls_server() -> (impl Listener, u16, Arc<Mutex<Option<String>>>) {
+    let identity = Arc::new(Mutex::new(None));
+    let config = Config {
+        psk: Some(Arc::new({
+            let identity = identity.clone();
+            move |hint: &[u8]| -> Result<Vec<u8>, DtlsError> {
+                *identity.lock().unwrap() = Some(String::from_utf8_lossy(hint).to_string());
+                Ok((0..CLIENT_KEY.len())
+                    .step_by(2)
+                    .map(|i| u8::from_str_radix(&CLIENT_KEY[i..i + 2], 16).unwrap())
+                    .collect())
+            }
+        })),
+        psk_identity_hint: Some(Vec::new()),
+        cipher_suites: vec![CipherSuiteId::Tls_Psk_With_Aes_128_Gcm_Sha256],
+        ..Default::default()
+    };
+    let listener = listen("127.0.0.1:0", config).await.unwrap();
+    let port = listener.addr().await.unwrap().port();
+    (listener, port, identity)
+}
+
+fn expected_message() -> Vec<u8> {
+    let mut message = b"HueStream".to_vec();
+    message.extend_from_slice(&[0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    message.extend_from_slice(CONFIGURATION_ID.as_bytes());
+    message.extend_from_slice(&[0x00, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00]);
+    message.extend_from_slice(&[0x03, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff]);
+    message
+}
+
+async fn start(url: String, port: u16) -> philipshue::entertainment::EntertainmentStream {
+    let hue = Hue::new(HueBridge::new(url), "username".to_string())
+        .with_client_key(CLIENT_KEY.to_string());
+    let stream = hue
+        .start_entertainment(
+            CONFIGURATION_ID,
+            EntertainmentOptions::new()
+                .port(port)
+                .frame_interval(Duration::from_millis(10)),
+        )
+        .await
+        .unwrap();
+    stream
+        .set_frame(
+            &Frame::new()
+                .channel(0, ChannelColor::rgb(255, 0, 0))
+                .channel(3, ChannelColor::rgb(0, 0, 255)),
+        )
+        .unwrap();
+    stream
+}
+
+#[tokio::test]
+async fn streams_huestream_messages() {
+    let (url, mut requests) = bridge().await;
+    let (listener, port, identity) = dtls_server().await;
+    let (stream, (connection, _)) =
+        tokio::join!(start(url, port), async { listener.accept().await.unwrap() });
+    assert_eq!(identity.lock().unwrap().as_deref(), Some("username"));
+
+    let path = format!("/clip/v2/resource/entertainment_configuration/{CONFIGURATION_ID}");
+    let (request_line, body) = requests.recv().await.unwrap();
+    assert_eq!(request_line, format!("PUT {path} HTTP/1.1"));
+    assert_eq!(body, r#"{"action":"start"}"#);
+
+    // the frame is repeated until the stream is stopped
+    let mut buffer = [0; 1024];
+    for _ in 0..3 {
+        let length = connection.recv(&mut buffer).await.unwrap();
+        assert_eq!(buffer[..length], expected_message());
+    }
+
+    stream.stop().await.unwrap();
+    let (request_line, body) = requests.recv().await.unwrap();
+    assert_eq!(request_line, format!("PUT {path} HTTP/1.1"));
+    assert_eq!(body, r#"{"action":"stop"}"#);
+}
+
+#[tokio::test]
+async fn dropping_the_stream_stops_sending() {
+    let (url, _requests) = bridge().await;
+    let (listener, port, _) = dtls_server().await;
+    let (stream, (connection, _)) =
+        tokio::join!(start(url, port), async { listener.accept().await.unwrap() });
+    let mut buffer = [0; 1024];
+    connection.recv(&mut buffer).await.unwrap();
+
+    drop(stream);
+    // the frames that were sent before the stream was dropped may still arrive
+    let stopped = tokio::time::timeout(Duration::from_secs(5), async {
+        while let Ok(Ok(_)) =
+            tokio::time::timeout(Duration::from_millis(200), connection.recv(&mut buffer)).await
+        {
+        }
+    })
+    .await;
+    assert!(
+        stopped.is_ok(),
+        "the frames are still sent after the stream was dropped"
+    );
+}
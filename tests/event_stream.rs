@@ -0,0 +1,136 @@
+use std::time::{Duration, Instant};
+
+use futures_util::StreamExt;
+use philipshue::{
+    v2::{EventType, HueV2, ResourceType},
+    HueBridge, HueError,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+};
+
+const EVENT_STREAM_HEADERS: &str =
+    "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n";
+
+/// Read the request head and return it in lowercase
+async fn read_request(socket: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+    while !request.ends_with(b"\r\n\r\n") {
+        let read = socket.read(&mut buffer).await.unwrap();
+        assert!(
+            read > 0,
+            "the connection was closed before the request was sent"
+        );
+        request.extend_from_slice(&buffer[..read]);
+    }
+    String::from_utf8(request).unwrap().to_lowercase()
+}
+
+fn last_event_id(request: &str) -> Option<&str> {
+    request
+        .lines()
+        .find_map(|line| line.strip_prefix("last-event-id: "))
+}
+
+/// Start a bridge that answers every connection with the next response and reports the requests
+async fn bridge(
+    responses: Vec<&'static str>,
+) -> (HueV2, mpsc::UnboundedReceiver<(String, Instant)>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (requests, received) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let mut open_connections = Vec::new();
+        for response in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let request = read_request(&mut socket).await;
+            let _ = requests.send((request, Instant::now()));
+            socket.write_all(response.as_bytes()).await.unwrap();
+            if response.starts_with(EVENT_STREAM_HEADERS) && response.ends_with("\n\n") {
+                socket.shutdown().await.unwrap();
+            } else {
+                // keep the connection open until the test ends
+                open_connections.push(socket);
+            }
+        }
+        std::future::pending::<()>().await;
+    });
+    (
+        HueV2::new(HueBridge::new(url), "username".to_string()),
+        received,
+    )
+}
+
+#[tokio::test]
+async fn reconnects_and_resumes_after_the_last_event() {
+    let (hue, mut requests) = bridge(vec![
+        "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        concat!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n",
+            ": hi\n\n",
+            "retry: 300\n",
+            "id: 1700000000:0\n",
+            "data: [{\"creationtime\": \"2023-11-14T22:13:20Z\", \"id\": \"b8d5a9a6-0000-4000-8000-000000000001\", \"type\": \"update\",\n",
+            "data: \"data\": [{\"id\": \"3f7b8c4e-1a2b-4c5d-9e8f-0a1b2c3d4e5f\", \"id_v1\": \"/lights/1\", \"type\": \"light\", \"on\": {\"on\": true}}]}]\n",
+            "\n",
+        ),
+        concat!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n",
+            "id: 1700000001:0\n",
+            "data: [{\"creationtime\": \"2023-11-14T22:13:21Z\", \"id\": \"b8d5a9a6-0000-4000-8000-000000000002\", \"type\": \"delete\", ",
+            "\"data\": [{\"id\": \"3f7b8c4e-1a2b-4c5d-9e8f-0a1b2c3d4e5f\", \"type\": \"light\"}]}]\n",
+            "\n",
+        ),
+    ])
+    .await;
+    let mut events = hue
+        .event_stream()
+        .reconnect_delay(Duration::from_millis(10))
+        .stream();
+
+    // the 503 is yielded as an error but doesn't end the stream
+    assert!(matches!(
+        events.next().await,
+        Some(Err(HueError::RequestError(_)))
+    ));
+    let (request, _) = requests.recv().await.unwrap();
+    assert!(request.starts_with("get /eventstream/clip/v2 "));
+    assert!(request.contains("hue-application-key: username\r\n"));
+    assert_eq!(last_event_id(&request), None);
+
+    // the data lines are joined
+    let event = events.next().await.unwrap().unwrap();
+    assert_eq!(event.event_type, EventType::Update);
+    assert_eq!(event.last_event_id.as_deref(), Some("1700000000:0"));
+    assert_eq!(event.resource.resource_type, ResourceType::Light);
+    assert_eq!(event.resource.id_v1.as_deref(), Some("/lights/1"));
+    assert_eq!(event.resource.attributes["on"]["on"], true);
+    let (request, _) = requests.recv().await.unwrap();
+    assert_eq!(last_event_id(&request), None);
+
+    // the stream reconnects after the `retry` delay and resumes after the last event
+    let disconnected = Instant::now();
+    let event = events.next().await.unwrap().unwrap();
+    assert_eq!(event.event_type, EventType::Delete);
+    assert_eq!(event.last_event_id.as_deref(), Some("1700000001:0"));
+    let (request, reconnected) = requests.recv().await.unwrap();
+    assert_eq!(last_event_id(&request), Some("1700000000:0"));
+    assert!(reconnected.duration_since(disconnected) >= Duration::from_millis(300));
+}
+
+#[tokio::test]
+async fn ends_if_the_username_is_rejected() {
+    let (hue, _requests) = bridge(vec![
+        "HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+    ])
+    .await;
+    let mut events = hue.event_stream().stream();
+    assert!(matches!(
+        events.next().await,
+        Some(Err(HueError::RequestError(_)))
+    ));
+    assert!(events.next().await.is_none());
+}
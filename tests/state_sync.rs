@@ -0,0 +1,180 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use philipshue::{
+    state::{BridgeState, ChangeKind, ResourceChange, ResourceKey},
+    Hue, HueBridge, HueError,
+};
+use serde_json::json;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+const EVENT_STREAM_HEADERS: &str =
+    "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n";
+
+const EVENTS: &str = concat!(
+    "id: 1700000000:0\n",
+    // the device shares the v1 id of the light but doesn't change it
+    "data: [{\"creationtime\": \"2023-11-14T22:13:20Z\", \"id\": \"b8d5a9a6-0000-4000-8000-000000000001\", \"type\": \"update\", ",
+    "\"data\": [{\"id\": \"5c2d1e0f-0000-4000-8000-000000000001\", \"id_v1\": \"/lights/1\", \"type\": \"device\"}]}]\n",
+    "\n",
+    "id: 1700000001:0\n",
+    "data: [{\"creationtime\": \"2023-11-14T22:13:21Z\", \"id\": \"b8d5a9a6-0000-4000-8000-000000000002\", \"type\": \"update\", ",
+    "\"data\": [{\"id\": \"3f7b8c4e-1a2b-4c5d-9e8f-0a1b2c3d4e5f\", \"id_v1\": \"/lights/1\", \"type\": \"light\", \"on\": {\"on\": true}}]}]\n",
+    "\n",
+    "id: 1700000002:0\n",
+    "data: [{\"creationtime\": \"2023-11-14T22:13:22Z\", \"id\": \"b8d5a9a6-0000-4000-8000-000000000003\", \"type\": \"delete\", ",
+    "\"data\": [{\"id\": \"3f7b8c4e-1a2b-4c5d-9e8f-0a1b2c3d4e60\", \"id_v1\": \"/lights/2\", \"type\": \"light\"}]}]\n",
+    "\n",
+);
+
+fn light(name: &str, on: bool) -> serde_json::Value {
+    json!({
+        "state": {
+            "on": on,
+            "bri": 254,
+            "alert": "select",
+            "mode": "homeautomation",
+            "reachable": true
+        },
+        "type": "Dimmable light",
+        "name": name,
+        "modelid": "LWB010",
+        "manufacturername": "Signify Netherlands B.V.",
+        "productname": "Hue white lamp",
+        "swupdate": { "state": "noupdates", "lastinstall": "2023-11-14T22:13:20" },
+        "uniqueid": "00:17:88:01:00:00:00:01-0b",
+        "swversion": "1.104.2",
+        "capabilities": {
+            "certified": true,
+            "control": {},
+            "streaming": { "renderer": false, "proxy": false }
+        },
+        "config": { "archetype": "classicbulb", "function": "functional", "direction": "omnidirectional" }
+    })
+}
+
+fn json_response(body: &serde_json::Value) -> String {
+    let body = body.to_string();
+    format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+/// Read the request head and return the path
+async fn read_path(socket: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+    while !request.ends_with(b"\r\n\r\n") {
+        let read = socket.read(&mut buffer).await.unwrap();
+        assert!(
+            read > 0,
+            "the connection was closed before the request was sent"
+        );
+        request.extend_from_slice(&buffer[..read]);
+    }
+    let request = String::from_utf8(request).unwrap();
+    request.split(' ').nth(1).unwrap().to_string()
+}
+
+/// Start a bridge with the v1 api and the event stream `event_stream`.
+/// The light `1` is turned on when the event stream is connected, the group `1` can't be parsed
+async fn bridge(event_stream: String) -> Hue {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let light_on = Arc::new(AtomicBool::new(false));
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let light_on = light_on.clone();
+            let event_stream = event_stream.clone();
+            tokio::spawn(async move {
+                let path = read_path(&mut socket).await;
+                let on = light_on.load(Ordering::SeqCst);
+                let response = match path.as_str() {
+                    "/eventstream/clip/v2" => {
+                        light_on.store(true, Ordering::SeqCst);
+                        socket.write_all(event_stream.as_bytes()).await.unwrap();
+                        // keep the connection open until the test ends
+                        std::future::pending::<()>().await;
+                        unreachable!()
+                    }
+                    "/api/username/lights" => json_response(&json!({
+                        "1": light("Kitchen", on),
+                        "2": light("Hallway", false)
+                    })),
+                    "/api/username/lights/1" => json_response(&light("Kitchen", on)),
+                    "/api/username/groups" => json_response(&json!({ "1": { "name": 1 } })),
+                    "/api/username/scenes" | "/api/username/sensors" => json_response(&json!({})),
+                    path => panic!("unexpected request to {path}"),
+                };
+                socket.write_all(response.as_bytes()).await.unwrap();
+            });
+        }
+    });
+    Hue::new(HueBridge::new(url), "username".to_string())
+}
+
+#[tokio::test]
+async fn events_update_the_state() {
+    let hue = bridge(format!("{EVENT_STREAM_HEADERS}{EVENTS}")).await;
+
+    // the group that can't be parsed doesn't keep the other resources from being loaded
+    let state = BridgeState::new(hue).await.unwrap();
+    assert!(!state.light("1").unwrap().state.on);
+    assert!(state.light("2").is_some());
+    assert!(state.groups().is_empty());
+
+    let mut watcher = state.watch(ResourceKey::light("1"));
+    let mut changes = state.subscribe();
+    let sync = tokio::spawn({
+        let state = state.clone();
+        async move { state.sync_events().await }
+    });
+
+    let changed = tokio::time::timeout(Duration::from_secs(5), watcher.changed()).await;
+    assert_eq!(changed.unwrap(), Some(ChangeKind::Updated));
+    assert!(state.light("1").unwrap().state.on);
+
+    // the device event is ignored, the light is reloaded and the other light is deleted
+    let mut received = Vec::new();
+    for _ in 0..2 {
+        let change = tokio::time::timeout(Duration::from_secs(5), changes.recv()).await;
+        received.push(change.unwrap().unwrap());
+    }
+    assert_eq!(
+        received,
+        vec![
+            ResourceChange {
+                key: ResourceKey::light("1"),
+                kind: ChangeKind::Updated,
+            },
+            ResourceChange {
+                key: ResourceKey::light("2"),
+                kind: ChangeKind::Removed,
+            },
+        ]
+    );
+    assert!(state.light("2").is_none());
+    assert!(!sync.is_finished());
+    sync.abort();
+}
+
+#[tokio::test]
+async fn ends_if_the_username_is_rejected() {
+    let hue = bridge(
+        "HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+    )
+    .await;
+    let state = BridgeState::new(hue).await.unwrap();
+    let result = tokio::time::timeout(Duration::from_secs(5), state.sync_events()).await;
+    assert!(matches!(result, Ok(Err(HueError::RequestError(_)))));
+}